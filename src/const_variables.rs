@@ -0,0 +1,71 @@
+use std::fmt;
+use std::ops::{Add, Mul};
+
+use crate::untyped_monome::UntypedMonome;
+use crate::untyped_polynome::UntypedPolynome;
+
+/// A zero-sized variable whose index is part of its type.
+///
+/// Two `Var`s with different indices are different types, so fixed-arity
+/// code can catch "wrong variable" mistakes at compile time instead of
+/// runtime. Every operation converts into the same runtime representation
+/// as [`variables::Var`], so the two styles mix freely in one expression.
+///
+/// ```
+/// use rust_polynomes::const_variables::{X, Y};
+/// use rust_polynomes::UntypedMonome;
+///
+/// let monome: UntypedMonome = X * Y * rust_polynomes::variables::X;
+/// assert_eq!(monome.powers, vec![(0, 2), (1, 1)]);
+/// ```
+///
+/// [`variables::Var`]: crate::variables::Var
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Var<const INDEX: usize>;
+
+/// The variable `x_0`, as a compile-time type.
+pub const X: Var<0> = Var;
+/// The variable `x_1`, as a compile-time type.
+pub const Y: Var<1> = Var;
+/// The variable `x_2`, as a compile-time type.
+pub const Z: Var<2> = Var;
+
+impl<const INDEX: usize> From<Var<INDEX>> for crate::variables::Var {
+    fn from(_: Var<INDEX>) -> Self {
+        crate::variables::Var(INDEX)
+    }
+}
+
+impl<const INDEX: usize> From<Var<INDEX>> for UntypedMonome {
+    fn from(_: Var<INDEX>) -> Self {
+        crate::variables::Var(INDEX).into()
+    }
+}
+
+impl<const INDEX: usize> From<Var<INDEX>> for UntypedPolynome {
+    fn from(_: Var<INDEX>) -> Self {
+        crate::variables::Var(INDEX).into()
+    }
+}
+
+impl<const INDEX: usize, T: Into<UntypedMonome>> Mul<T> for Var<INDEX> {
+    type Output = UntypedMonome;
+
+    fn mul(self, rhs: T) -> UntypedMonome {
+        UntypedMonome::from(self) * rhs
+    }
+}
+
+impl<const INDEX: usize, T: Into<UntypedPolynome>> Add<T> for Var<INDEX> {
+    type Output = UntypedPolynome;
+
+    fn add(self, rhs: T) -> UntypedPolynome {
+        UntypedPolynome::from(self) + rhs.into()
+    }
+}
+
+impl<const INDEX: usize> fmt::Display for Var<INDEX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", crate::variables::Var(INDEX))
+    }
+}
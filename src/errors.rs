@@ -0,0 +1,165 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::variables::Var;
+
+/// Errors produced when substituting values into a polynome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubstitutionError {
+    /// A variable of the polynome was not given a value; carries its index.
+    MissingVariable(usize),
+    /// The same variable was supplied more than once; carries its index.
+    RepeatingVariable(usize),
+    /// A supplied variable never appears in the polynome. Only the strict
+    /// [`TypedPolynome::try_substitute`] reports this; plain `substitute`
+    /// ignores extra values.
+    ///
+    /// [`TypedPolynome::try_substitute`]: crate::TypedPolynome::try_substitute
+    UnknownVariable(Var),
+}
+
+/// Errors produced by division operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivisionError {
+    /// Attempted to divide by zero.
+    ZeroDivisor,
+    /// The operation requires a polynome univariate in the chosen variable,
+    /// but another variable occurred; carries the offending index.
+    NotUnivariate(usize),
+}
+
+/// Errors produced when parsing a polynome from text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Input ended where a coefficient, variable or exponent was expected.
+    UnexpectedEnd,
+    /// An unrecognized token; carries the offending fragment.
+    UnknownToken(String),
+    /// A coefficient failed to parse in the coefficient type.
+    InvalidCoefficient(String),
+    /// An exponent was not a valid unsigned integer.
+    InvalidExponent(String),
+}
+
+/// Errors produced when an expansion grows beyond a caller-supplied bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpansionError {
+    /// An intermediate result exceeded the allowed number of terms.
+    TooManyTerms,
+}
+
+impl fmt::Display for SubstitutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubstitutionError::MissingVariable(index) => {
+                write!(f, "missing variable {}", Var(*index))
+            }
+            SubstitutionError::RepeatingVariable(index) => {
+                write!(f, "repeating variable {}", Var(*index))
+            }
+            SubstitutionError::UnknownVariable(var) => {
+                write!(f, "unknown variable {}", var)
+            }
+        }
+    }
+}
+
+impl Error for SubstitutionError {}
+
+impl fmt::Display for DivisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DivisionError::ZeroDivisor => write!(f, "division by zero"),
+            DivisionError::NotUnivariate(index) => {
+                write!(f, "polynome is not univariate: found {}", Var(*index))
+            }
+        }
+    }
+}
+
+impl Error for DivisionError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ParseError::UnknownToken(token) => write!(f, "unknown token \"{}\"", token),
+            ParseError::InvalidCoefficient(text) => {
+                write!(f, "invalid coefficient \"{}\"", text)
+            }
+            ParseError::InvalidExponent(text) => write!(f, "invalid exponent \"{}\"", text),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl fmt::Display for ExpansionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpansionError::TooManyTerms => write!(f, "expansion exceeded the term bound"),
+        }
+    }
+}
+
+impl Error for ExpansionError {}
+
+/// A unified error for callers propagating mixed polynome failures with
+/// `?` into one type; each operation-specific error converts via `From`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolynomeError {
+    /// See [`SubstitutionError`].
+    Substitution(SubstitutionError),
+    /// See [`DivisionError`].
+    Division(DivisionError),
+    /// See [`ParseError`].
+    Parse(ParseError),
+    /// See [`ExpansionError`].
+    Expansion(ExpansionError),
+}
+
+impl From<SubstitutionError> for PolynomeError {
+    fn from(error: SubstitutionError) -> Self {
+        PolynomeError::Substitution(error)
+    }
+}
+
+impl From<DivisionError> for PolynomeError {
+    fn from(error: DivisionError) -> Self {
+        PolynomeError::Division(error)
+    }
+}
+
+impl From<ParseError> for PolynomeError {
+    fn from(error: ParseError) -> Self {
+        PolynomeError::Parse(error)
+    }
+}
+
+impl From<ExpansionError> for PolynomeError {
+    fn from(error: ExpansionError) -> Self {
+        PolynomeError::Expansion(error)
+    }
+}
+
+impl fmt::Display for PolynomeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolynomeError::Substitution(error) => error.fmt(f),
+            PolynomeError::Division(error) => error.fmt(f),
+            PolynomeError::Parse(error) => error.fmt(f),
+            PolynomeError::Expansion(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for PolynomeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PolynomeError::Substitution(error) => Some(error),
+            PolynomeError::Division(error) => Some(error),
+            PolynomeError::Parse(error) => Some(error),
+            PolynomeError::Expansion(error) => Some(error),
+        }
+    }
+}
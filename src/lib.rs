@@ -0,0 +1,37 @@
+//! Sparse multivariate polynomes over generic semiring coefficients.
+//!
+//! The crate is split into an *untyped* layer, which tracks only the variable
+//! structure of monomes, and a *typed* layer attaching coefficients from any
+//! [`traits::CommutativeSemiring`]. Expressions are built with the usual
+//! operators starting from the variable constants:
+//!
+//! ```
+//! use rust_polynomes::variables::{X, Y};
+//! use rust_polynomes::{Coeff, TypedPolynome};
+//!
+//! let polynome: TypedPolynome<u32> = Coeff(2u32) * X * Y + X + Y;
+//! assert_eq!(polynome.substitute(vec![(X, 1u32), (Y, 2u32)]), Ok(7u32));
+//! ```
+
+pub mod const_variables;
+pub mod errors;
+pub mod semirings;
+pub mod ordering;
+pub mod symmetric;
+pub mod traits;
+pub mod variables;
+
+mod map_polynome;
+mod parsing;
+mod typed_monome;
+mod typed_polynome;
+mod untyped_monome;
+mod untyped_polynome;
+
+pub use map_polynome::MapPolynome;
+pub use typed_monome::{Coeff, TypedMonome};
+pub use typed_polynome::{interpolate, jacobian, TypedPolynome, TypedPolynomeBuilder};
+#[cfg(feature = "rand")]
+pub use typed_polynome::random_polynome;
+pub use untyped_monome::UntypedMonome;
+pub use untyped_polynome::UntypedPolynome;
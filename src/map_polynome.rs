@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+use std::ops::{Add, Mul};
+
+use crate::traits::CommutativeSemiring;
+use crate::typed_monome::TypedMonome;
+use crate::typed_polynome::TypedPolynome;
+use crate::untyped_monome::UntypedMonome;
+
+/// A polynome keyed by monome for O(log n) term lookup and insertion.
+///
+/// [`TypedPolynome`] stores a `Vec` that is fast for bulk arithmetic but
+/// linear for `coefficient_of`-style queries; this parallel representation
+/// keeps terms merged in a `BTreeMap` instead, for workloads dominated by
+/// lookups and incremental insertion. Convert freely in both directions;
+/// the map iterates in the crate's canonical monomial order, so the
+/// conversion back yields an already ordered [`TypedPolynome`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MapPolynome<T: CommutativeSemiring> {
+    pub terms: BTreeMap<UntypedMonome, T>,
+}
+
+impl<T: CommutativeSemiring> MapPolynome<T> {
+    /// The zero polynome, containing no terms.
+    pub fn new() -> Self {
+        Self {
+            terms: BTreeMap::new(),
+        }
+    }
+
+    /// Adds `coeff * vars` into the polynome, merging with an existing term
+    /// for the same monome and dropping the entry when the sum cancels to
+    /// zero. O(log n).
+    pub fn add_term(&mut self, vars: UntypedMonome, coeff: T) {
+        let vars = vars.normalized();
+        let merged = match self.terms.remove(&vars) {
+            Some(existing) => existing + coeff,
+            None => coeff,
+        };
+        if !merged.is_zero() {
+            self.terms.insert(vars, merged);
+        }
+    }
+
+    /// Returns the coefficient of the monome, if present. O(log n).
+    pub fn get(&self, vars: &UntypedMonome) -> Option<&T> {
+        self.terms.get(&vars.normalized())
+    }
+
+    /// Removes the term for the monome and returns its coefficient.
+    /// O(log n).
+    pub fn remove(&mut self, vars: &UntypedMonome) -> Option<T> {
+        self.terms.remove(&vars.normalized())
+    }
+}
+
+impl<T: CommutativeSemiring> From<TypedPolynome<T>> for MapPolynome<T> {
+    fn from(polynome: TypedPolynome<T>) -> Self {
+        let mut answer = MapPolynome::new();
+        for monome in polynome.monomes {
+            answer.add_term(monome.vars, monome.coeff);
+        }
+        answer
+    }
+}
+
+impl<T: CommutativeSemiring> From<MapPolynome<T>> for TypedPolynome<T> {
+    /// The map iterates in the canonical monomial order, so the resulting
+    /// polynome is already in [`TypedPolynome::order`]-ed form.
+    fn from(polynome: MapPolynome<T>) -> Self {
+        TypedPolynome {
+            monomes: polynome
+                .terms
+                .into_iter()
+                .map(|(vars, coeff)| TypedMonome { coeff, vars })
+                .collect(),
+        }
+    }
+}
+
+impl<T: CommutativeSemiring> Add for MapPolynome<T> {
+    type Output = MapPolynome<T>;
+
+    /// Merges the terms of both operands, keeping the result canonical.
+    fn add(mut self, rhs: MapPolynome<T>) -> MapPolynome<T> {
+        for (vars, coeff) in rhs.terms {
+            self.add_term(vars, coeff);
+        }
+        self
+    }
+}
+
+impl<T: CommutativeSemiring> Mul for MapPolynome<T> {
+    type Output = MapPolynome<T>;
+
+    /// Multiplies term-by-term, merging like products as they are
+    /// inserted.
+    fn mul(self, rhs: MapPolynome<T>) -> MapPolynome<T> {
+        let mut answer = MapPolynome::new();
+        for (vars, coeff) in &self.terms {
+            for (other_vars, other_coeff) in &rhs.terms {
+                answer.add_term(
+                    vars.clone() * other_vars.clone(),
+                    coeff.clone() * other_coeff.clone(),
+                );
+            }
+        }
+        answer
+    }
+}
@@ -0,0 +1,70 @@
+use std::cmp::Ordering;
+
+use crate::untyped_monome::UntypedMonome;
+
+/// Monomial orders understood by [`TypedPolynome::order_by`].
+///
+/// [`TypedPolynome::order_by`]: crate::TypedPolynome::order_by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonomialOrder {
+    /// Lexicographic by the sorted `powers` list, the crate's default.
+    Lex,
+    /// Total degree first, ties broken lexicographically.
+    DegLex,
+    /// Total degree first, ties broken reverse-lexicographically: among
+    /// equal degrees the monome with the smaller power in the last
+    /// differing variable is the greater one.
+    DegRevLex,
+}
+
+impl MonomialOrder {
+    /// Compares two monomes under the chosen order. Variables absent from a
+    /// monome are treated as having power zero.
+    pub fn compare(self, a: &UntypedMonome, b: &UntypedMonome) -> Ordering {
+        match self {
+            MonomialOrder::Lex => a.cmp(b),
+            MonomialOrder::DegLex => a.degree().cmp(&b.degree()).then_with(|| a.cmp(b)),
+            MonomialOrder::DegRevLex => a
+                .degree()
+                .cmp(&b.degree())
+                .then_with(|| Self::reverse_lexicographic(a, b)),
+        }
+    }
+
+    fn reverse_lexicographic(a: &UntypedMonome, b: &UntypedMonome) -> Ordering {
+        let mut left = a.powers.iter().rev().peekable();
+        let mut right = b.powers.iter().rev().peekable();
+        loop {
+            match (left.peek(), right.peek()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Greater,
+                (Some(_), None) => return Ordering::Less,
+                (Some(&&(a_index, a_power)), Some(&&(b_index, b_power))) => {
+                    match a_index.cmp(&b_index) {
+                        // The monome with power zero at the highest differing
+                        // variable has the smaller power there, so it wins.
+                        Ordering::Greater => return Ordering::Less,
+                        Ordering::Less => return Ordering::Greater,
+                        Ordering::Equal if a_power != b_power => return b_power.cmp(&a_power),
+                        Ordering::Equal => {
+                            left.next();
+                            right.next();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Compares two monomes under the crate's default [`MonomialOrder::Lex`],
+/// the same key [`TypedPolynome::order`] sorts by.
+///
+/// Use this to key external sorted structures such as a
+/// `BTreeMap<UntypedMonome, T>` consistently with the crate's canonical
+/// form, without depending on the derived `Ord` of the `powers` layout.
+///
+/// [`TypedPolynome::order`]: crate::TypedPolynome::order
+pub fn monomial_cmp(a: &UntypedMonome, b: &UntypedMonome) -> Ordering {
+    MonomialOrder::Lex.compare(a, b)
+}
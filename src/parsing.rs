@@ -0,0 +1,174 @@
+//! Text parsing for typed polynomes.
+
+use std::str::FromStr;
+
+use crate::errors::ParseError;
+use crate::traits::CommutativeSemiring;
+use crate::typed_monome::TypedMonome;
+use crate::typed_polynome::TypedPolynome;
+use crate::untyped_monome::UntypedMonome;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Plus,
+    Minus,
+    Star,
+    Caret,
+    Number(String),
+    Ident(String),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&symbol) = chars.peek() {
+        match symbol {
+            symbol if symbol.is_whitespace() => {
+                chars.next();
+            }
+            '+' => {
+                chars.next();
+                tokens.push(Token::Plus);
+            }
+            '-' => {
+                chars.next();
+                tokens.push(Token::Minus);
+            }
+            '*' => {
+                chars.next();
+                tokens.push(Token::Star);
+            }
+            '^' => {
+                chars.next();
+                tokens.push(Token::Caret);
+            }
+            symbol if symbol.is_ascii_digit() => {
+                let mut number = String::new();
+                while let Some(&digit) = chars.peek() {
+                    if digit.is_ascii_digit() || digit == '.' {
+                        number.push(digit);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Number(number));
+            }
+            symbol if symbol.is_alphabetic() => {
+                let mut ident = String::new();
+                while let Some(&letter) = chars.peek() {
+                    if letter.is_alphanumeric() || letter == '_' {
+                        ident.push(letter);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            other => return Err(ParseError::UnknownToken(other.to_string())),
+        }
+    }
+    Ok(tokens)
+}
+
+fn variable_index(name: &str) -> Result<usize, ParseError> {
+    match name {
+        "x" => Ok(0),
+        "y" => Ok(1),
+        "z" => Ok(2),
+        other => Err(ParseError::UnknownToken(other.to_string())),
+    }
+}
+
+/// Parses expressions like `2*x^2 + 3*x - 1` into a polynome, mapping `x`,
+/// `y` and `z` to `Var(0)`, `Var(1)` and `Var(2)`.
+///
+/// Coefficients of one may be left implicit (`x^2 + x`). A minus sign
+/// applies to the whole term, so `-x` and `-x*2` only parse for coefficient
+/// types whose `FromStr` accepts `-1`. The result is ordered.
+impl<T: CommutativeSemiring + FromStr> FromStr for TypedPolynome<T> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Err(ParseError::UnexpectedEnd);
+        }
+        let mut answer = TypedPolynome::zero();
+        let mut position = 0;
+        loop {
+            let mut negative = false;
+            if position > 0 && tokens.get(position) == Some(&Token::Plus) {
+                position += 1;
+            }
+            // A minus may follow a plus: `Display` renders negative
+            // coefficients as `+ -3*x_1`, and that must parse back.
+            if tokens.get(position) == Some(&Token::Minus) {
+                negative = true;
+                position += 1;
+            }
+            let mut coeff: Option<T> = None;
+            let mut powers: Vec<(usize, usize)> = Vec::new();
+            loop {
+                match tokens.get(position) {
+                    Some(Token::Number(text)) => {
+                        position += 1;
+                        let factor = T::from_str(text)
+                            .map_err(|_| ParseError::InvalidCoefficient(text.clone()))?;
+                        coeff = Some(match coeff {
+                            Some(so_far) => so_far * factor,
+                            None => factor,
+                        });
+                    }
+                    Some(Token::Ident(name)) => {
+                        position += 1;
+                        let index = variable_index(name)?;
+                        let mut power = 1;
+                        if tokens.get(position) == Some(&Token::Caret) {
+                            position += 1;
+                            match tokens.get(position) {
+                                Some(Token::Number(text)) => {
+                                    position += 1;
+                                    power = usize::from_str(text)
+                                        .map_err(|_| ParseError::InvalidExponent(text.clone()))?;
+                                }
+                                Some(_) | None => return Err(ParseError::UnexpectedEnd),
+                            }
+                        }
+                        powers.push((index, power));
+                    }
+                    Some(other) => {
+                        return Err(ParseError::UnknownToken(format!("{:?}", other)));
+                    }
+                    None => return Err(ParseError::UnexpectedEnd),
+                }
+                if tokens.get(position) == Some(&Token::Star) {
+                    position += 1;
+                } else {
+                    break;
+                }
+            }
+            let mut coeff = match coeff {
+                Some(coeff) => coeff,
+                None => T::one(),
+            };
+            if negative {
+                let minus_one = T::from_str("-1")
+                    .map_err(|_| ParseError::InvalidCoefficient("-1".to_string()))?;
+                coeff = minus_one * coeff;
+            }
+            answer.monomes.push(TypedMonome {
+                coeff,
+                vars: UntypedMonome { powers }.normalized(),
+            });
+            match tokens.get(position) {
+                None => break,
+                Some(Token::Plus) | Some(Token::Minus) => {}
+                Some(other) => return Err(ParseError::UnknownToken(format!("{:?}", other))),
+            }
+        }
+        answer.order();
+        Ok(answer)
+    }
+}
@@ -0,0 +1,55 @@
+//! Example coefficient types for exotic semirings.
+//!
+//! The crate's arithmetic is generic over [`traits::Semiring`], which is
+//! satisfied by any type with the right `Add`/`Mul`/`Zero`/`One` — not
+//! just the numeric ones. This module holds a worked example to copy when
+//! wiring up your own ring.
+//!
+//! [`traits::Semiring`]: crate::traits::Semiring
+
+use std::ops::{Add, Mul};
+
+use num_traits::{One, Zero};
+
+/// The tropical (min-plus) semiring over `f64`.
+///
+/// Addition is `min`, multiplication is `+`, so the additive identity is
+/// `+∞` and the multiplicative identity is `0.0`. Substituting edge
+/// weights into a polynome whose monomes enumerate paths computes the
+/// shortest-path weight.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Tropical(pub f64);
+
+impl Add for Tropical {
+    type Output = Tropical;
+
+    fn add(self, rhs: Tropical) -> Tropical {
+        Tropical(self.0.min(rhs.0))
+    }
+}
+
+impl Mul for Tropical {
+    type Output = Tropical;
+
+    // Min-plus multiplication really is `+`; the lint expects numeric Mul.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Tropical) -> Tropical {
+        Tropical(self.0 + rhs.0)
+    }
+}
+
+impl Zero for Tropical {
+    fn zero() -> Tropical {
+        Tropical(f64::INFINITY)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == f64::INFINITY
+    }
+}
+
+impl One for Tropical {
+    fn one() -> Tropical {
+        Tropical(0.0)
+    }
+}
@@ -0,0 +1,72 @@
+//! Constructors for the classical symmetric polynomes.
+//!
+//! Building `xy + xz + yz` by hand is error prone once the variable set
+//! grows; these helpers produce the standard families ready to promote to
+//! a typed coefficient with [`UntypedPolynome::into_typed`].
+//!
+//! [`UntypedPolynome::into_typed`]: crate::UntypedPolynome::into_typed
+
+use crate::untyped_monome::UntypedMonome;
+use crate::untyped_polynome::UntypedPolynome;
+use crate::variables::Var;
+
+/// Returns the elementary symmetric polynome `e_k` over `vars`: the sum of
+/// all squarefree degree-`k` products of distinct variables. For
+/// `vars = [X, Y, Z]` and `k = 2` that is `xy + xz + yz`.
+///
+/// `e_0` is the unit monome and `k` beyond the number of variables yields
+/// the empty polynome. The result is ordered; duplicate entries in `vars`
+/// are ignored.
+pub fn elementary_symmetric(vars: &[Var], k: usize) -> UntypedPolynome {
+    let mut indices: Vec<usize> = vars.iter().map(|var| var.0).collect();
+    indices.sort_unstable();
+    indices.dedup();
+    let mut answer = UntypedPolynome {
+        monomes: vec![UntypedMonome::default()],
+    };
+    for _ in 0..k {
+        let mut extended = Vec::new();
+        for monome in &answer.monomes {
+            let largest = monome.powers.last().map(|&(index, _)| index);
+            for &index in &indices {
+                if largest.is_none_or(|used| index > used) {
+                    extended.push(monome.clone() * Var(index));
+                }
+            }
+        }
+        answer.monomes = extended;
+    }
+    answer.order();
+    answer
+}
+
+/// Returns the power sum `p_k` over `vars`: the sum of every variable
+/// raised to the `k`-th power, `x^k + y^k + z^k` for `vars = [X, Y, Z]`.
+///
+/// `p_0` is one unit monome per distinct variable — duplicate monomes are
+/// first-class at the untyped layer, so
+/// [`UntypedPolynome::count_terms`] recovers the variable count. The
+/// result is ordered; duplicate entries in `vars` are ignored.
+///
+/// [`UntypedPolynome::count_terms`]: crate::UntypedPolynome::count_terms
+pub fn power_sum(vars: &[Var], k: usize) -> UntypedPolynome {
+    let mut indices: Vec<usize> = vars.iter().map(|var| var.0).collect();
+    indices.sort_unstable();
+    indices.dedup();
+    let mut answer = UntypedPolynome {
+        monomes: indices
+            .into_iter()
+            .map(|index| {
+                if k == 0 {
+                    UntypedMonome::default()
+                } else {
+                    UntypedMonome {
+                        powers: vec![(index, k)],
+                    }
+                }
+            })
+            .collect(),
+    };
+    answer.order();
+    answer
+}
@@ -0,0 +1,103 @@
+use std::ops::{Add, Div, Mul, Neg};
+
+use num_traits::{CheckedMul, One, Zero};
+
+/// The contract coefficient types must satisfy.
+///
+/// A semiring has associative addition and multiplication with respective
+/// identities [`Zero`] and [`One`]; subtraction is deliberately not required
+/// so that unsigned integers qualify. Only `Clone` is demanded, so
+/// heap-backed coefficients such as `num_bigint::BigInt` qualify alongside
+/// the `Copy` numeric types.
+pub trait Semiring: Clone + Add<Output = Self> + Mul<Output = Self> + Zero + One {}
+
+impl<T: Clone + Add<Output = T> + Mul<Output = T> + Zero + One> Semiring for T {}
+
+/// Marker for semirings whose multiplication commutes.
+///
+/// All polynome arithmetic assumes commutative coefficients.
+pub trait CommutativeSemiring: Semiring {}
+
+impl<T: Semiring> CommutativeSemiring for T {}
+
+/// Marker for coefficient types that also support exact division and
+/// negation, such as `f64` or `num_rational::Ratio<i64>`.
+///
+/// Division-based polynome methods like [`TypedPolynome::div_rem`] are
+/// gated on this trait so that their requirements are visible in one place.
+///
+/// [`TypedPolynome::div_rem`]: crate::TypedPolynome::div_rem
+pub trait Field: Semiring + Div<Output = Self> + Neg<Output = Self> {}
+
+impl<T: Semiring + Div<Output = T> + Neg<Output = T>> Field for T {}
+
+/// Marker for semirings whose multiplication can report overflow, such as
+/// the fixed-width integers.
+///
+/// [`TypedMonome::checked_pow`] is gated on this trait; the plain `Pow`
+/// impls stay wrapping.
+///
+/// [`TypedMonome::checked_pow`]: crate::TypedMonome::checked_pow
+pub trait CheckedSemiring: Semiring + CheckedMul {}
+
+impl<T: Semiring + CheckedMul> CheckedSemiring for T {}
+
+/// Coefficient types with a greatest common divisor, used by
+/// [`TypedPolynome::common_monomial_factor`] to factor a scalar out of
+/// every term.
+///
+/// Unlike the other traits in this module this one has no blanket impl: a
+/// GCD is only meaningful for integer-like types. Types where it is
+/// undefined implement it as a constant [`One::one`], claiming no common
+/// factor, which is what the float impls below do.
+///
+/// [`TypedPolynome::common_monomial_factor`]: crate::TypedPolynome::common_monomial_factor
+pub trait GcdSemiring: Semiring {
+    /// Returns the greatest common divisor of the two values; `gcd(0, x)`
+    /// is `x`, and the result is non-negative for signed types.
+    fn gcd(&self, other: &Self) -> Self;
+}
+
+macro_rules! impl_signed_gcd {
+    ($($t:ty),*) => {$(
+        impl GcdSemiring for $t {
+            fn gcd(&self, other: &Self) -> Self {
+                let (mut a, mut b) = (*self, *other);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a.abs()
+            }
+        }
+    )*};
+}
+
+impl_signed_gcd!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_unsigned_gcd {
+    ($($t:ty),*) => {$(
+        impl GcdSemiring for $t {
+            fn gcd(&self, other: &Self) -> Self {
+                let (mut a, mut b) = (*self, *other);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+        }
+    )*};
+}
+
+impl_unsigned_gcd!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_trivial_gcd {
+    ($($t:ty),*) => {$(
+        impl GcdSemiring for $t {
+            fn gcd(&self, _: &Self) -> Self {
+                Self::one()
+            }
+        }
+    )*};
+}
+
+impl_trivial_gcd!(f32, f64);
@@ -0,0 +1,236 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num_traits::Pow;
+
+use crate::traits::{CheckedSemiring, CommutativeSemiring};
+use crate::typed_polynome::TypedPolynome;
+use crate::untyped_monome::UntypedMonome;
+use crate::variables::Var;
+
+/// Wrapper marking a raw value as a coefficient.
+///
+/// Needed to disambiguate `Coeff(2u32) * X` from plain scalar arithmetic
+/// when building typed expressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Coeff<T>(pub T);
+
+/// A monome together with a coefficient of type `T`.
+///
+/// ```
+/// use rust_polynomes::variables::{X, Y};
+/// use rust_polynomes::{Coeff, TypedMonome};
+///
+/// let monome: TypedMonome<u32> = Coeff(3u32) * X * Y;
+/// assert_eq!(monome.coeff, 3u32);
+/// assert_eq!(monome.vars.powers, vec![(0, 1), (1, 1)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TypedMonome<T: CommutativeSemiring> {
+    pub coeff: T,
+    pub vars: UntypedMonome,
+}
+
+impl<T: CommutativeSemiring> TypedMonome<T> {
+    /// Builds a monome from a coefficient and a variable part, restoring
+    /// the sorted non-repeating invariant on `vars` first.
+    ///
+    /// Prefer this over a literal `TypedMonome { .. }` for externally
+    /// supplied variable parts: repeated or unsorted indices make
+    /// multiplication and substitution misbehave.
+    pub fn new(coeff: T, mut vars: UntypedMonome) -> Self {
+        vars.canonicalize();
+        TypedMonome { coeff, vars }
+    }
+
+    /// Returns the total degree of the variable part.
+    pub fn degree(&self) -> usize {
+        self.vars.degree()
+    }
+
+    /// Returns a reference to the coefficient.
+    pub fn coeff(&self) -> &T {
+        &self.coeff
+    }
+
+    /// Iterates over the `(variable, power)` pairs of the variable part in
+    /// index order without cloning; see [`UntypedMonome::iter_powers`].
+    pub fn iter_powers(&self) -> impl Iterator<Item = (Var, usize)> + '_ {
+        self.vars.iter_powers()
+    }
+
+    /// Raises the monome to a power like [`Pow`] without consuming it; see
+    /// [`TypedPolynome::pow_ref`].
+    ///
+    /// [`TypedPolynome::pow_ref`]: crate::TypedPolynome::pow_ref
+    pub fn pow_ref(&self, exp: usize) -> TypedMonome<T> {
+        self.clone().pow(exp)
+    }
+
+    /// Compares two monomes by their variable part alone, the key
+    /// [`TypedPolynome::order`] sorts by.
+    ///
+    /// The derived `Ord` is structural and compares `coeff` first, which
+    /// mixes coefficient magnitude into the term order; use this when the
+    /// monomial itself should decide.
+    ///
+    /// [`TypedPolynome::order`]: crate::TypedPolynome::order
+    pub fn cmp_by_vars(&self, other: &Self) -> Ordering {
+        self.vars.cmp(&other.vars)
+    }
+
+    /// Splits off `var`, returning its power and the monome with `var`
+    /// removed. The power is zero when `var` does not occur; a variable
+    /// part with repeated indices is normalized first so the power comes
+    /// out summed rather than last-wins.
+    pub fn extract_variable(&self, var: Var) -> (usize, TypedMonome<T>) {
+        let vars = self.vars.normalized();
+        let mut extracted = 0;
+        let mut powers = Vec::with_capacity(vars.powers.len());
+        for &(index, power) in &vars.powers {
+            if index == var.0 {
+                extracted = power;
+            } else {
+                powers.push((index, power));
+            }
+        }
+        (
+            extracted,
+            TypedMonome {
+                coeff: self.coeff.clone(),
+                vars: UntypedMonome { powers },
+            },
+        )
+    }
+}
+
+impl<T: CommutativeSemiring> From<Coeff<T>> for TypedMonome<T> {
+    fn from(coeff: Coeff<T>) -> Self {
+        Self {
+            coeff: coeff.0,
+            vars: UntypedMonome::default(),
+        }
+    }
+}
+
+impl<T: CommutativeSemiring, U: Into<UntypedMonome>> From<U> for TypedMonome<T> {
+    /// Attaches the unit coefficient to an untyped monome or variable.
+    fn from(vars: U) -> Self {
+        Self {
+            coeff: T::one(),
+            vars: vars.into(),
+        }
+    }
+}
+
+impl<T: CommutativeSemiring, U: Into<TypedMonome<T>>> Mul<U> for Coeff<T> {
+    type Output = TypedMonome<T>;
+
+    fn mul(self, rhs: U) -> TypedMonome<T> {
+        let rhs: TypedMonome<T> = rhs.into();
+        TypedMonome {
+            coeff: self.0 * rhs.coeff,
+            vars: rhs.vars,
+        }
+    }
+}
+
+impl<T: CommutativeSemiring, U: Into<TypedMonome<T>>> Mul<U> for TypedMonome<T> {
+    type Output = TypedMonome<T>;
+
+    fn mul(self, rhs: U) -> TypedMonome<T> {
+        let rhs: TypedMonome<T> = rhs.into();
+        TypedMonome {
+            coeff: self.coeff * rhs.coeff,
+            vars: self.vars * rhs.vars,
+        }
+    }
+}
+
+impl<T: CommutativeSemiring + Neg<Output = T>> Neg for TypedMonome<T> {
+    type Output = TypedMonome<T>;
+
+    fn neg(self) -> TypedMonome<T> {
+        TypedMonome {
+            coeff: -self.coeff,
+            vars: self.vars,
+        }
+    }
+}
+
+impl<T: CommutativeSemiring, U: Into<TypedPolynome<T>>> Add<U> for TypedMonome<T> {
+    type Output = TypedPolynome<T>;
+
+    fn add(self, rhs: U) -> TypedPolynome<T> {
+        TypedPolynome::from(self) + rhs.into()
+    }
+}
+
+impl<T: CommutativeSemiring, U: Into<TypedPolynome<T>>> Add<U> for Coeff<T> {
+    type Output = TypedPolynome<T>;
+
+    fn add(self, rhs: U) -> TypedPolynome<T> {
+        TypedPolynome::from(self) + rhs.into()
+    }
+}
+
+impl<T: CommutativeSemiring + Neg<Output = T>, U: Into<TypedPolynome<T>>> Sub<U>
+    for TypedMonome<T>
+{
+    type Output = TypedPolynome<T>;
+
+    /// Subtraction lifts to a polynome, mirroring [`Add`], so expressions
+    /// like `Coeff(3) * X - Coeff(2) * X` read naturally.
+    fn sub(self, rhs: U) -> TypedPolynome<T> {
+        TypedPolynome::from(self) - rhs.into()
+    }
+}
+
+impl<T: CommutativeSemiring + Neg<Output = T>, U: Into<TypedPolynome<T>>> Sub<U> for Coeff<T> {
+    type Output = TypedPolynome<T>;
+
+    fn sub(self, rhs: U) -> TypedPolynome<T> {
+        TypedPolynome::from(self) - rhs.into()
+    }
+}
+
+impl<T: CheckedSemiring> TypedMonome<T> {
+    /// Raises the monome to a power like [`Pow`], but returns `None` when
+    /// raising the coefficient overflows instead of silently wrapping.
+    pub fn checked_pow(self, pow: usize) -> Option<TypedMonome<T>> {
+        let mut coeff = T::one();
+        for _ in 0..pow {
+            coeff = coeff.checked_mul(&self.coeff)?;
+        }
+        Some(TypedMonome {
+            coeff,
+            vars: self.vars.pow(pow),
+        })
+    }
+}
+
+impl<T: CommutativeSemiring> Pow<usize> for TypedMonome<T> {
+    type Output = TypedMonome<T>;
+
+    fn pow(self, pow: usize) -> TypedMonome<T> {
+        let mut coeff = T::one();
+        for _ in 0..pow {
+            coeff = coeff * self.coeff.clone();
+        }
+        TypedMonome {
+            coeff,
+            vars: self.vars.pow(pow),
+        }
+    }
+}
+
+impl<T: CommutativeSemiring + fmt::Display> fmt::Display for TypedMonome<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.vars.powers.is_empty() {
+            write!(f, "{}", self.coeff)
+        } else {
+            write!(f, "{}*{}", self.coeff, self.vars)
+        }
+    }
+}
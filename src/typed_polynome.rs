@@ -0,0 +1,2242 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::{One, Pow, Zero};
+
+use crate::errors::{DivisionError, ExpansionError, SubstitutionError};
+use crate::ordering::MonomialOrder;
+use crate::traits::{CommutativeSemiring, Field, GcdSemiring};
+use crate::typed_monome::{Coeff, TypedMonome};
+use crate::untyped_monome::UntypedMonome;
+use crate::untyped_polynome::UntypedPolynome;
+use crate::variables::{Var, VarPool};
+
+/// A sum of [`TypedMonome`]s over coefficients of type `T`.
+///
+/// The `monomes` vec is not kept normalized by arithmetic; call [`order`]
+/// to sort terms, merge duplicates and drop zero coefficients.
+///
+/// The derived `Ord`/`PartialOrd` are structural-only: they compare the
+/// raw `monomes` vec, so two mathematically equal but differently ordered
+/// polynomes compare unequal. Normalize first, or use
+/// [`cmp_normalized`], before relying on the ordering — e.g. for a
+/// `BTreeSet<TypedPolynome<T>>`.
+///
+/// ```
+/// use rust_polynomes::variables::{X, Y};
+/// use rust_polynomes::{Coeff, TypedPolynome};
+///
+/// let mut polynome: TypedPolynome<u32> = ((X + Y) * (X + Y)).into();
+/// polynome.order();
+/// let mut expected = Coeff(1u32) * X * X + Coeff(2u32) * X * Y + Coeff(1u32) * Y * Y;
+/// expected.order();
+/// assert_eq!(polynome, expected);
+/// ```
+///
+/// [`order`]: TypedPolynome::order
+/// [`cmp_normalized`]: TypedPolynome::cmp_normalized
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TypedPolynome<T: CommutativeSemiring> {
+    pub monomes: Vec<TypedMonome<T>>,
+}
+
+impl<T: CommutativeSemiring> TypedPolynome<T> {
+    /// The zero polynome, containing no monomes.
+    pub fn zero() -> Self {
+        Self { monomes: vec![] }
+    }
+
+    /// The constant polynome equal to one.
+    pub fn one() -> Self {
+        Self {
+            monomes: vec![TypedMonome::from(Coeff(T::one()))],
+        }
+    }
+
+    /// Sorts monomes by their variable part via
+    /// [`TypedMonome::cmp_by_vars`], merges like terms and drops zero
+    /// coefficients, bringing the polynome to canonical form under the
+    /// default [`MonomialOrder::Lex`].
+    pub fn order(&mut self) {
+        self.monomes.sort_by(TypedMonome::cmp_by_vars);
+        self.merge_sorted();
+    }
+
+    /// Like [`order`], but sorts under the chosen monomial order.
+    ///
+    /// [`order`]: TypedPolynome::order
+    pub fn order_by(&mut self, ord: MonomialOrder) {
+        self.monomes.sort_by(|a, b| ord.compare(&a.vars, &b.vars));
+        self.merge_sorted();
+    }
+
+    /// Merges adjacent like terms and drops zero coefficients; the monomes
+    /// must already be sorted by their variable part.
+    fn merge_sorted(&mut self) {
+        let mut merged: Vec<TypedMonome<T>> = Vec::with_capacity(self.monomes.len());
+        for monome in self.monomes.drain(..) {
+            match merged.last_mut() {
+                Some(last) if last.vars == monome.vars => {
+                    last.coeff = last.coeff.clone() + monome.coeff;
+                }
+                _ => merged.push(monome),
+            }
+        }
+        merged.retain(|monome| !monome.coeff.is_zero());
+        self.monomes = merged;
+    }
+
+    /// Returns the maximal total degree among the monomes, zero for the
+    /// zero polynome.
+    pub fn degree(&self) -> usize {
+        self.monomes
+            .iter()
+            .map(|monome| monome.degree())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Divides every coefficient by `divisor`, failing with
+    /// [`DivisionError::ZeroDivisor`] when the divisor is zero.
+    ///
+    /// This is the building block for making a polynome monic by dividing
+    /// through by its leading coefficient.
+    pub fn div_scalar(&self, divisor: T) -> Result<TypedPolynome<T>, DivisionError>
+    where
+        T: Field,
+    {
+        if divisor.is_zero() {
+            return Err(DivisionError::ZeroDivisor);
+        }
+        Ok(TypedPolynome {
+            monomes: self
+                .monomes
+                .iter()
+                .map(|monome| TypedMonome {
+                    coeff: monome.coeff.clone() / divisor.clone(),
+                    vars: monome.vars.clone(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns the dense coefficient vector of a polynome univariate in
+    /// `var`, indexed by power with the constant term at index zero.
+    ///
+    /// Fails with [`SubstitutionError::MissingVariable`] if any monome
+    /// references a variable other than `var`.
+    pub fn to_coefficients(&self, var: Var) -> Result<Vec<T>, SubstitutionError> {
+        let mut coefficients = vec![T::zero(); self.degree_in(var) + 1];
+        for monome in &self.monomes {
+            for &(index, _) in &monome.vars.powers {
+                if index != var.0 {
+                    return Err(SubstitutionError::MissingVariable(index));
+                }
+            }
+            // The buffer is sized by degree_in, which ignores zero
+            // coefficients, so a zero term of higher degree must not index
+            // into it.
+            if monome.coeff.is_zero() {
+                continue;
+            }
+            let power = monome.vars.degree_in(var);
+            coefficients[power] = coefficients[power].clone() + monome.coeff.clone();
+        }
+        Ok(coefficients)
+    }
+
+    /// Builds a polynome univariate in `var` from a dense coefficient
+    /// vector indexed by power, dropping zero coefficients.
+    pub fn from_coefficients(var: Var, coefficients: Vec<T>) -> TypedPolynome<T> {
+        TypedPolynome {
+            monomes: coefficients
+                .into_iter()
+                .enumerate()
+                .filter(|(_, coeff)| !coeff.is_zero())
+                .map(|(power, coeff)| TypedMonome {
+                    coeff,
+                    vars: UntypedMonome {
+                        powers: if power == 0 {
+                            vec![]
+                        } else {
+                            vec![(var.0, power)]
+                        },
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    /// Like [`to_coefficients`] but with trailing zeros trimmed and the
+    /// error mapped into the division vocabulary.
+    ///
+    /// [`to_coefficients`]: TypedPolynome::to_coefficients
+    fn dense_coefficients(&self, var: Var) -> Result<Vec<T>, DivisionError> {
+        let mut coefficients = self.to_coefficients(var).map_err(|error| match error {
+            SubstitutionError::MissingVariable(index) => DivisionError::NotUnivariate(index),
+            SubstitutionError::RepeatingVariable(index) => DivisionError::NotUnivariate(index),
+            SubstitutionError::UnknownVariable(var) => DivisionError::NotUnivariate(var.0),
+        })?;
+        while coefficients.last().is_some_and(|last| last.is_zero()) {
+            coefficients.pop();
+        }
+        Ok(coefficients)
+    }
+
+    /// Divides the whole polynome by its leading coefficient in `var`,
+    /// the standard normalization before GCD comparisons and root finding:
+    /// `3x^2 + 6x` becomes `x^2 + 2x`.
+    ///
+    /// Returns `None` when the polynome is zero or not univariate in
+    /// `var`. The result is in ordered form.
+    pub fn make_monic(&self, var: Var) -> Option<TypedPolynome<T>>
+    where
+        T: Field,
+    {
+        let coefficients = self.dense_coefficients(var).ok()?;
+        let leading = coefficients.last()?.clone();
+        self.div_scalar(leading).ok().map(TypedPolynome::ordered)
+    }
+
+    /// Euclidean division of polynomes univariate in `var`; returns the
+    /// `(quotient, remainder)` pair with `deg(remainder) < deg(divisor)`.
+    ///
+    /// Requires field coefficients. Fails with
+    /// [`DivisionError::ZeroDivisor`] when `divisor` is zero and
+    /// [`DivisionError::NotUnivariate`] when either side mentions a
+    /// variable other than `var`.
+    pub fn div_rem(&self, divisor: &Self, var: Var) -> Result<(Self, Self), DivisionError>
+    where
+        T: Field,
+    {
+        let mut remainder = self.dense_coefficients(var)?;
+        let divisor = divisor.dense_coefficients(var)?;
+        let Some(leading) = divisor.last() else {
+            return Err(DivisionError::ZeroDivisor);
+        };
+        let mut quotient = vec![T::zero(); (remainder.len() + 1).saturating_sub(divisor.len())];
+        while remainder.len() >= divisor.len() {
+            let offset = remainder.len() - divisor.len();
+            let factor = remainder.last().unwrap().clone() / leading.clone();
+            for (position, coeff) in divisor.iter().enumerate() {
+                remainder[offset + position] = remainder[offset + position].clone()
+                    + -(factor.clone() * coeff.clone());
+            }
+            quotient[offset] = factor;
+            remainder.pop();
+            while remainder.last().is_some_and(|last| last.is_zero()) {
+                remainder.pop();
+            }
+        }
+        Ok((
+            Self::from_coefficients(var, quotient),
+            Self::from_coefficients(var, remainder),
+        ))
+    }
+
+    /// Returns the monic greatest common divisor of two polynomes
+    /// univariate in `var`, computed with the Euclidean algorithm.
+    ///
+    /// Coprime inputs yield [`TypedPolynome::one`]; the GCD with the zero
+    /// polynome is the other operand made monic.
+    pub fn gcd(&self, other: &TypedPolynome<T>, var: Var) -> Result<TypedPolynome<T>, DivisionError>
+    where
+        T: Field,
+    {
+        let mut first = self.clone();
+        first.order();
+        let mut second = other.clone();
+        second.order();
+        while !second.monomes.is_empty() {
+            let remainder = first.div_rem(&second, var)?.1;
+            first = second;
+            second = remainder;
+        }
+        if first.monomes.is_empty() {
+            return Ok(first);
+        }
+        let leading = first
+            .dense_coefficients(var)?
+            .last()
+            .cloned()
+            .expect("non-zero polynome has a leading coefficient");
+        first.div_scalar(leading)
+    }
+
+    /// Applies `f` to every coefficient, preserving the variable structure.
+    ///
+    /// Useful for converting between coefficient types or reducing modulo a
+    /// prime. Zero results are kept; use [`map_coefficients_reduced`] to
+    /// drop them.
+    ///
+    /// [`map_coefficients_reduced`]: TypedPolynome::map_coefficients_reduced
+    pub fn map_coefficients<S: CommutativeSemiring>(
+        &self,
+        f: impl Fn(&T) -> S,
+    ) -> TypedPolynome<S> {
+        TypedPolynome {
+            monomes: self
+                .monomes
+                .iter()
+                .map(|monome| TypedMonome {
+                    coeff: f(&monome.coeff),
+                    vars: monome.vars.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Like [`map_coefficients`], but additionally orders the result so
+    /// zeros produced by `f` are dropped and like terms merged.
+    ///
+    /// [`map_coefficients`]: TypedPolynome::map_coefficients
+    pub fn map_coefficients_reduced<S: CommutativeSemiring>(
+        &self,
+        f: impl Fn(&T) -> S,
+    ) -> TypedPolynome<S> {
+        let mut answer = self.map_coefficients(f);
+        answer.order();
+        answer
+    }
+
+    /// Returns whether the two polynomes are mathematically equal, i.e.
+    /// equal after both are brought to canonical ordered form.
+    ///
+    /// The derived `==` stays structural: it is sensitive to term order and
+    /// to un-combined duplicate terms, so `X + Y != Y + X` under `==` while
+    /// `(X + Y).equivalent(&(Y + X))` holds.
+    pub fn equivalent(&self, other: &Self) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut lhs = self.clone();
+        lhs.order();
+        let mut rhs = other.clone();
+        rhs.order();
+        lhs == rhs
+    }
+
+    /// Returns the coefficient attached to the monome `vars`, summing over
+    /// duplicate terms if the polynome is not ordered, or zero if absent.
+    ///
+    /// Both sides are compared in normalized form, so `X * Y` and `Y * X`
+    /// describe the same monome.
+    pub fn coefficient_of(&self, vars: &UntypedMonome) -> T {
+        let target = vars.normalized();
+        let mut answer = T::zero();
+        for monome in &self.monomes {
+            if monome.vars.normalized() == target {
+                answer = answer + monome.coeff.clone();
+            }
+        }
+        answer
+    }
+
+    /// Returns whether `var` occurs in any monome with a non-zero
+    /// coefficient, short-circuiting on the first match.
+    pub fn contains_variable(&self, var: Var) -> bool {
+        self.monomes
+            .iter()
+            .any(|monome| !monome.coeff.is_zero() && monome.vars.contains_variable(var))
+    }
+
+    /// Rewrites every variable of every monome through `f`; see
+    /// [`UntypedMonome::remap_variables`] for the collision rules.
+    pub fn remap_variables(&mut self, f: impl Fn(Var) -> Var) {
+        for monome in &mut self.monomes {
+            monome.vars.remap_variables(&f);
+        }
+    }
+
+    /// Renames a single variable, a shorthand over [`remap_variables`].
+    ///
+    /// [`remap_variables`]: TypedPolynome::remap_variables
+    pub fn rename_variable(&mut self, from: Var, to: Var) {
+        self.remap_variables(|var| if var == from { to } else { var });
+    }
+
+    /// Returns whether every non-zero monome is variable-free; true for the
+    /// zero polynome.
+    pub fn is_constant(&self) -> bool {
+        self.monomes
+            .iter()
+            .all(|monome| monome.coeff.is_zero() || monome.vars.powers.is_empty())
+    }
+
+    /// Returns the constant term: the sum of the coefficients of all
+    /// variable-free monomes, zero if there are none.
+    pub fn constant_term(&self) -> T {
+        let mut answer = T::zero();
+        for monome in &self.monomes {
+            if monome.vars.powers.is_empty() {
+                answer = answer + monome.coeff.clone();
+            }
+        }
+        answer
+    }
+
+    /// Returns whether all non-zero monomes share the same total degree;
+    /// true for the zero polynome.
+    pub fn is_homogeneous(&self) -> bool {
+        let mut degrees = self
+            .monomes
+            .iter()
+            .filter(|monome| !monome.coeff.is_zero())
+            .map(|monome| monome.degree());
+        match degrees.next() {
+            Some(first) => degrees.all(|degree| degree == first),
+            None => true,
+        }
+    }
+
+    /// Returns the sorted, deduplicated set of variables appearing in any
+    /// non-zero monome; empty for the zero polynome and for constants.
+    pub fn variables(&self) -> Vec<Var> {
+        let mut indices: Vec<usize> = self
+            .monomes
+            .iter()
+            .filter(|monome| !monome.coeff.is_zero())
+            .flat_map(|monome| monome.vars.powers.iter().map(|&(index, _)| index))
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices.into_iter().map(Var).collect()
+    }
+
+    /// Returns the largest variable index appearing in any non-zero
+    /// monome, `None` for the zero polynome and for constants.
+    ///
+    /// Sized dense evaluation buffers should span `0..=max_variable`; see
+    /// [`TypedPolynome::variables`] for the full sparse set.
+    pub fn max_variable(&self) -> Option<Var> {
+        self.monomes
+            .iter()
+            .filter(|monome| !monome.coeff.is_zero())
+            .flat_map(|monome| monome.vars.powers.iter().map(|&(index, _)| index))
+            .max()
+            .map(Var)
+    }
+
+    /// Returns the maximal power of `var` among the non-zero monomes, zero
+    /// for the zero polynome and for polynomes not containing `var`.
+    pub fn degree_in(&self, var: Var) -> usize {
+        self.monomes
+            .iter()
+            .filter(|monome| !monome.coeff.is_zero())
+            .map(|monome| monome.vars.degree_in(var))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the sum of all coefficients, which equals the evaluation
+    /// `p(1, 1, ..., 1)` without building a substitution list covering
+    /// every variable. The zero polynome sums to `zero()`.
+    pub fn sum_of_coefficients(&self) -> T {
+        self.monomes
+            .iter()
+            .fold(T::zero(), |answer, monome| answer + monome.coeff.clone())
+    }
+
+    /// Divides every term by the monomial `coeff * vars`, subtracting
+    /// exponents and dividing coefficients: `x^2*y + x*y` divided by `x*y`
+    /// yields `x + 1`.
+    ///
+    /// More general than [`TypedPolynome::div_scalar`] since it also
+    /// strips variables. Returns `None` when `coeff` is zero or any term
+    /// has a smaller power in some variable of `vars`, keeping the
+    /// operation total.
+    pub fn divide_by_monomial(&self, coeff: T, vars: &UntypedMonome) -> Option<TypedPolynome<T>>
+    where
+        T: Field,
+    {
+        if coeff.is_zero() {
+            return None;
+        }
+        let divisor = vars.normalized();
+        let mut monomes = Vec::with_capacity(self.monomes.len());
+        for monome in &self.monomes {
+            let mut remaining = monome.vars.normalized().powers;
+            for &(index, power) in &divisor.powers {
+                let entry = remaining
+                    .iter_mut()
+                    .find(|&&mut (candidate, _)| candidate == index)?;
+                entry.1 = entry.1.checked_sub(power)?;
+            }
+            remaining.retain(|&(_, power)| power > 0);
+            monomes.push(TypedMonome {
+                coeff: monome.coeff.clone() / coeff.clone(),
+                vars: UntypedMonome { powers: remaining },
+            });
+        }
+        Some(TypedPolynome { monomes })
+    }
+
+    /// Returns the largest monomial dividing every term together with the
+    /// GCD of the coefficients, so the common factor can be split off:
+    /// `2x^2*y + 4x*y` yields `(2, x*y)`, leaving `x + 2` after
+    /// [`TypedPolynome::divide_by_monomial`].
+    ///
+    /// The coefficient part comes from [`GcdSemiring`] and is the constant
+    /// one for types where a GCD is undefined, such as the floats. The
+    /// zero polynome yields `(zero, 1)`.
+    pub fn common_monomial_factor(&self) -> (T, UntypedMonome)
+    where
+        T: GcdSemiring,
+    {
+        let monomes = self.normalized().monomes;
+        let coeff = monomes
+            .iter()
+            .fold(T::zero(), |answer, monome| answer.gcd(&monome.coeff));
+        let mut powers = match monomes.first() {
+            Some(monome) => monome.vars.powers.clone(),
+            None => return (coeff, UntypedMonome::default()),
+        };
+        for monome in &monomes[1..] {
+            powers.retain_mut(|(index, power)| {
+                match monome
+                    .vars
+                    .powers
+                    .iter()
+                    .find(|&&(candidate, _)| candidate == *index)
+                {
+                    Some(&(_, other)) => {
+                        *power = (*power).min(other);
+                        true
+                    }
+                    None => false,
+                }
+            });
+        }
+        (coeff, UntypedMonome { powers })
+    }
+
+    /// Subtracts `other` term-by-term, returning `None` if any resulting
+    /// coefficient would go negative.
+    ///
+    /// This is the meaningful subtraction for semiring coefficients like
+    /// the unsigned integers, where the `Neg`-based `Sub` operator does
+    /// not even compile; for ring coefficients prefer plain `-`.
+    pub fn checked_sub(&self, other: &Self) -> Option<TypedPolynome<T>>
+    where
+        T: Ord + std::ops::Sub<Output = T>,
+    {
+        let mut answer = self.normalized();
+        for monome in other.normalized().monomes {
+            match answer
+                .monomes
+                .iter_mut()
+                .find(|candidate| candidate.vars == monome.vars)
+            {
+                Some(candidate) if candidate.coeff >= monome.coeff => {
+                    candidate.coeff = candidate.coeff.clone() - monome.coeff;
+                }
+                _ => return None,
+            }
+        }
+        answer.monomes.retain(|monome| !monome.coeff.is_zero());
+        Some(answer)
+    }
+
+    /// Multiplies every coefficient by a raw `factor`, sparing the caller
+    /// the `Coeff` wrapper.
+    ///
+    /// A `Mul<T>` operator impl would collide with the blanket
+    /// `Mul<U: Into<TypedPolynome<T>>>`, so scaling by a bare coefficient
+    /// lives here as a method.
+    pub fn scale(&self, factor: T) -> Self {
+        TypedPolynome {
+            monomes: self
+                .monomes
+                .iter()
+                .map(|monome| TypedMonome {
+                    coeff: monome.coeff.clone() * factor.clone(),
+                    vars: monome.vars.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Negates every coefficient in place, reusing the existing term
+    /// storage; behaves exactly like `Neg` without the reallocation, which
+    /// matters in subtraction-heavy loops over large polynomes.
+    pub fn negate_in_place(&mut self)
+    where
+        T: Neg<Output = T>,
+    {
+        for monome in &mut self.monomes {
+            monome.coeff = -std::mem::replace(&mut monome.coeff, T::zero());
+        }
+    }
+
+    /// Lifts the polynome into a wider coefficient type, the intended
+    /// upgrade path for combining polynomes of mixed numeric widths:
+    /// `poly_i32.promote::<i64>() + poly_i64`.
+    pub fn promote<S: CommutativeSemiring + From<T>>(&self) -> TypedPolynome<S> {
+        TypedPolynome {
+            monomes: self
+                .monomes
+                .iter()
+                .map(|monome| TypedMonome {
+                    coeff: S::from(monome.coeff.clone()),
+                    vars: monome.vars.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the ordered, like-terms-combined, zeros-dropped form of the
+    /// polynome without mutating `self`; the owned counterpart of
+    /// [`TypedPolynome::order`].
+    pub fn normalized(&self) -> Self {
+        let mut normalized = self.clone();
+        normalized.order();
+        normalized
+    }
+
+    /// Compares two polynomes by their normalized term sequences under the
+    /// default [`MonomialOrder::Lex`], so equal-up-to-ordering polynomes
+    /// compare [`Ordering::Equal`] regardless of structural differences.
+    ///
+    /// [`Ordering::Equal`]: std::cmp::Ordering::Equal
+    pub fn cmp_normalized(&self, other: &Self) -> std::cmp::Ordering
+    where
+        T: Ord,
+    {
+        self.normalized().cmp(&other.normalized())
+    }
+
+    /// Consumes the polynome and returns it in ordered form; the
+    /// expression-position counterpart of [`TypedPolynome::order`].
+    pub fn ordered(mut self) -> Self {
+        self.order();
+        self
+    }
+
+    /// Returns the ordered form without consuming `self`; an alias of
+    /// [`TypedPolynome::normalized`] matching the untyped naming.
+    pub fn to_ordered(&self) -> Self {
+        self.normalized()
+    }
+
+    /// Iterates over the normalized terms in ascending total degree, ties
+    /// broken by [`MonomialOrder::DegLex`] — the arrangement for truncating
+    /// a series or reading off low-order behavior, as opposed to the
+    /// lexicographic arrangement of [`TypedPolynome::order`].
+    ///
+    /// The iterator owns a normalized snapshot, so duplicate and zero
+    /// terms never leak through even on an unordered polynome.
+    pub fn terms_by_degree(&self) -> impl Iterator<Item = TypedMonome<T>> {
+        let mut monomes = self.normalized().monomes;
+        monomes.sort_by(|a, b| MonomialOrder::DegLex.compare(&a.vars, &b.vars));
+        monomes.into_iter()
+    }
+
+    /// Appends terms built from coefficient and exponent-list pairs, the
+    /// bulk-insertion counterpart of [`TypedPolynomeBuilder`].
+    ///
+    /// Each exponent list is normalized into a monome, merging repeated
+    /// variables by summing; an empty list yields a constant term. The
+    /// polynome itself is left unordered — call [`TypedPolynome::order`]
+    /// once after the batch.
+    pub fn extend_terms(&mut self, terms: impl IntoIterator<Item = (T, Vec<(Var, usize)>)>) {
+        for (coeff, powers) in terms {
+            let vars = UntypedMonome::from_powers(
+                powers.into_iter().map(|(var, power)| (var.0, power)).collect(),
+            );
+            self.monomes.push(TypedMonome { coeff, vars });
+        }
+    }
+
+    /// Returns the number of terms after normalization, so duplicated
+    /// monomes are counted once and zero terms not at all.
+    ///
+    /// This is the honest sparsity measure; `monomes.len()` can overstate
+    /// it on unordered polynomes.
+    pub fn len(&self) -> usize {
+        let mut normalized = self.clone();
+        normalized.order();
+        normalized.monomes.len()
+    }
+
+    /// Returns `true` when the polynome normalizes to zero.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Evaluates the polynome at the given values.
+    ///
+    /// Every variable occurring in the polynome must be supplied exactly
+    /// once; extra values are ignored.
+    ///
+    /// Each term multiplies its factors in the order the caller listed the
+    /// values, which assumes multiplication in `U` commutes. For
+    /// non-commutative values such as matrices use
+    /// [`TypedPolynome::substitute_ordered`].
+    ///
+    /// ```
+    /// use rust_polynomes::variables::{X, Y};
+    /// use rust_polynomes::{Coeff, TypedPolynome};
+    ///
+    /// let polynome: TypedPolynome<u32> = Coeff(2u32) * X * X + Y;
+    /// assert_eq!(polynome.substitute(vec![(X, 3u32), (Y, 1u32)]), Ok(19u32));
+    /// ```
+    pub fn substitute<U>(&self, values: Vec<(Var, U)>) -> Result<U, SubstitutionError>
+    where
+        U: Add<Output = U> + Mul<Output = U> + Zero + One + Clone + From<T>,
+    {
+        for (position, (var, _)) in values.iter().enumerate() {
+            if values[..position].iter().any(|(other, _)| other == var) {
+                return Err(SubstitutionError::RepeatingVariable(var.0));
+            }
+        }
+        let mut answer = U::zero();
+        for monome in &self.monomes {
+            debug_assert!(
+                monome.vars.powers.windows(2).all(|pair| pair[0].0 < pair[1].0),
+                "monome powers must be sorted and unique; \
+                 build monomes through TypedMonome::new or UntypedMonome::from_powers"
+            );
+            for &(index, _) in &monome.vars.powers {
+                if !values.iter().any(|(var, _)| var.0 == index) {
+                    return Err(SubstitutionError::MissingVariable(index));
+                }
+            }
+            let mut term = U::one();
+            for (var, value) in &values {
+                let power = monome
+                    .vars
+                    .powers
+                    .iter()
+                    .find(|&&(index, _)| index == var.0)
+                    .map(|&(_, power)| power)
+                    .unwrap_or(0);
+                for _ in 0..power {
+                    term = term * value.clone();
+                }
+            }
+            answer = answer + U::from(monome.coeff.clone()) * term;
+        }
+        Ok(answer)
+    }
+
+    /// Evaluates the polynome like [`TypedPolynome::substitute`], but
+    /// strictly: a value for a variable that never appears in the polynome
+    /// fails with [`SubstitutionError::UnknownVariable`] instead of being
+    /// ignored, catching fabricated or stale [`Var`] indices at the call
+    /// site.
+    pub fn try_substitute<U>(&self, values: Vec<(Var, U)>) -> Result<U, SubstitutionError>
+    where
+        U: Add<Output = U> + Mul<Output = U> + Zero + One + Clone + From<T>,
+    {
+        for (var, _) in &values {
+            if !self.contains_variable(*var) {
+                return Err(SubstitutionError::UnknownVariable(*var));
+            }
+        }
+        self.substitute(values)
+    }
+
+    /// Evaluates the polynome like [`TypedPolynome::substitute`], but
+    /// multiplies the factors of every term in ascending variable-index
+    /// order, with the coefficient on the left.
+    ///
+    /// This gives a well-defined result when multiplication in `U` does not
+    /// commute; for commutative `U` it agrees with `substitute`.
+    pub fn substitute_ordered<U>(&self, values: Vec<(Var, U)>) -> Result<U, SubstitutionError>
+    where
+        U: Add<Output = U> + Mul<Output = U> + Zero + One + Clone + From<T>,
+    {
+        for (position, (var, _)) in values.iter().enumerate() {
+            if values[..position].iter().any(|(other, _)| other == var) {
+                return Err(SubstitutionError::RepeatingVariable(var.0));
+            }
+        }
+        let mut answer = U::zero();
+        for monome in &self.monomes {
+            let mut term = U::from(monome.coeff.clone());
+            for &(index, power) in &monome.vars.powers {
+                let value = values
+                    .iter()
+                    .find(|(var, _)| var.0 == index)
+                    .map(|(_, value)| value)
+                    .ok_or(SubstitutionError::MissingVariable(index))?;
+                for _ in 0..power {
+                    term = term * value.clone();
+                }
+            }
+            answer = answer + term;
+        }
+        Ok(answer)
+    }
+
+    /// Evaluates the polynome like [`TypedPolynome::substitute`], but
+    /// precomputes every needed power of each value once, combining halves
+    /// so `val^k` costs one multiply instead of `k - 1` per monome.
+    ///
+    /// Behavior is identical to `substitute`; only the multiply count
+    /// changes, which pays off for high-degree polynomes with shared
+    /// sub-products.
+    pub fn substitute_cached<U>(&self, values: Vec<(Var, U)>) -> Result<U, SubstitutionError>
+    where
+        U: Add<Output = U> + Mul<Output = U> + Zero + One + Clone + From<T>,
+    {
+        for (position, (var, _)) in values.iter().enumerate() {
+            if values[..position].iter().any(|(other, _)| other == var) {
+                return Err(SubstitutionError::RepeatingVariable(var.0));
+            }
+        }
+        let mut tables: HashMap<usize, Vec<U>> = HashMap::new();
+        for (var, value) in &values {
+            let max_power = self
+                .monomes
+                .iter()
+                .map(|monome| monome.vars.degree_in(*var))
+                .max()
+                .unwrap_or(0);
+            let mut table = Vec::with_capacity(max_power + 1);
+            table.push(U::one());
+            for power in 1..=max_power {
+                let entry = if power == 1 {
+                    value.clone()
+                } else {
+                    table[power / 2].clone() * table[power - power / 2].clone()
+                };
+                table.push(entry);
+            }
+            tables.insert(var.0, table);
+        }
+        let mut answer = U::zero();
+        for monome in &self.monomes {
+            let mut term = U::from(monome.coeff.clone());
+            for &(index, power) in &monome.vars.powers {
+                let table = tables
+                    .get(&index)
+                    .ok_or(SubstitutionError::MissingVariable(index))?;
+                term = term * table[power].clone();
+            }
+            answer = answer + term;
+        }
+        Ok(answer)
+    }
+
+    /// Evaluates the polynome with values taken from a map keyed by
+    /// variable index.
+    ///
+    /// A map cannot contain duplicates, so unlike [`substitute`] no
+    /// repeating-variable check is needed; any variable absent from the map
+    /// fails with [`SubstitutionError::MissingVariable`].
+    ///
+    /// [`substitute`]: TypedPolynome::substitute
+    pub fn substitute_map<U>(&self, values: &HashMap<usize, U>) -> Result<U, SubstitutionError>
+    where
+        U: Add<Output = U> + Mul<Output = U> + Zero + One + Clone + From<T>,
+    {
+        let mut answer = U::zero();
+        for monome in &self.monomes {
+            let mut term = U::from(monome.coeff.clone());
+            for &(index, power) in &monome.vars.powers {
+                let value = values
+                    .get(&index)
+                    .ok_or(SubstitutionError::MissingVariable(index))?;
+                for _ in 0..power {
+                    term = term * value.clone();
+                }
+            }
+            answer = answer + term;
+        }
+        Ok(answer)
+    }
+
+    /// Evaluates the polynome with values indexed by variable position,
+    /// for variables allocated contiguously from a [`VarPool`].
+    ///
+    /// The value of `Var(i)` is read straight from `values[i]`, so a hot
+    /// loop evaluating the same polynome millions of times pays no per-call
+    /// map construction. Fails with
+    /// [`SubstitutionError::MissingVariable`] when the slice is shorter
+    /// than the pool or the polynome mentions a variable outside it.
+    pub fn substitute_dense(&self, pool: &VarPool, values: &[T]) -> Result<T, SubstitutionError> {
+        if values.len() < pool.len() {
+            return Err(SubstitutionError::MissingVariable(values.len()));
+        }
+        let mut answer = T::zero();
+        for monome in &self.monomes {
+            let mut term = monome.coeff.clone();
+            for &(index, power) in &monome.vars.powers {
+                let value = values
+                    .get(index)
+                    .ok_or(SubstitutionError::MissingVariable(index))?;
+                for _ in 0..power {
+                    term = term * value.clone();
+                }
+            }
+            answer = answer + term;
+        }
+        Ok(answer)
+    }
+
+    /// Evaluates the polynome at every point in `points`, returning one
+    /// value per point.
+    ///
+    /// The term structure is flattened once and reused across all points,
+    /// which beats calling [`substitute`] in a loop when the polynome is
+    /// large and there are many points.
+    ///
+    /// [`substitute`]: TypedPolynome::substitute
+    pub fn substitute_many<U>(&self, points: &[Vec<(Var, U)>]) -> Result<Vec<U>, SubstitutionError>
+    where
+        U: Add<Output = U> + Mul<Output = U> + Zero + One + Clone + From<T>,
+    {
+        let terms: Vec<(U, &[(usize, usize)])> = self
+            .monomes
+            .iter()
+            .map(|monome| (U::from(monome.coeff.clone()), monome.vars.powers.as_slice()))
+            .collect();
+        let mut answers = Vec::with_capacity(points.len());
+        for point in points {
+            for (position, (var, _)) in point.iter().enumerate() {
+                if point[..position].iter().any(|(other, _)| other == var) {
+                    return Err(SubstitutionError::RepeatingVariable(var.0));
+                }
+            }
+            let values: HashMap<usize, &U> =
+                point.iter().map(|(var, value)| (var.0, value)).collect();
+            let mut answer = U::zero();
+            for (coeff, powers) in &terms {
+                let mut term = coeff.clone();
+                for &(index, power) in *powers {
+                    let value = *values
+                        .get(&index)
+                        .ok_or(SubstitutionError::MissingVariable(index))?;
+                    for _ in 0..power {
+                        term = term * value.clone();
+                    }
+                }
+                answer = answer + term;
+            }
+            answers.push(answer);
+        }
+        Ok(answers)
+    }
+
+    /// Evaluates a polynome univariate in `var` at `value` via Horner's
+    /// scheme, using one multiplication and one addition per degree.
+    ///
+    /// Fails with [`SubstitutionError::MissingVariable`] if any monome
+    /// references a variable other than `var`.
+    pub fn eval_horner(&self, var: Var, value: T) -> Result<T, SubstitutionError> {
+        let coefficients = self.to_coefficients(var)?;
+        let mut answer = T::zero();
+        for coefficient in coefficients.into_iter().rev() {
+            answer = answer * value.clone() + coefficient;
+        }
+        Ok(answer)
+    }
+
+    /// Plugs in values for a subset of the variables, keeping all other
+    /// variables symbolic.
+    ///
+    /// Unlike [`substitute`], variables without a supplied value simply stay
+    /// in place, so the call never fails with `MissingVariable`; supplying
+    /// the same variable twice is still rejected. The result is ordered.
+    ///
+    /// [`substitute`]: TypedPolynome::substitute
+    pub fn substitute_partial(
+        &self,
+        values: Vec<(Var, T)>,
+    ) -> Result<TypedPolynome<T>, SubstitutionError> {
+        for (position, (var, _)) in values.iter().enumerate() {
+            if values[..position].iter().any(|(other, _)| other == var) {
+                return Err(SubstitutionError::RepeatingVariable(var.0));
+            }
+        }
+        let mut answer = TypedPolynome::zero();
+        for monome in &self.monomes {
+            let mut coeff = monome.coeff.clone();
+            let mut powers = Vec::with_capacity(monome.vars.powers.len());
+            for &(index, power) in &monome.vars.powers {
+                match values.iter().find(|(var, _)| var.0 == index) {
+                    Some((_, value)) => {
+                        for _ in 0..power {
+                            coeff = coeff * value.clone();
+                        }
+                    }
+                    None => powers.push((index, power)),
+                }
+            }
+            answer.monomes.push(TypedMonome {
+                coeff,
+                vars: UntypedMonome { powers },
+            });
+        }
+        answer.order();
+        Ok(answer)
+    }
+
+    /// Applies several polynome substitutions simultaneously, replacing each
+    /// listed variable by its polynome without feeding results into each
+    /// other. Variables not listed stay in place; the result is ordered.
+    ///
+    /// Listing the same variable twice fails with
+    /// [`SubstitutionError::RepeatingVariable`].
+    pub fn compose(
+        &mut self,
+        substitutions: Vec<(Var, TypedPolynome<T>)>,
+    ) -> Result<(), SubstitutionError> {
+        for (position, (var, _)) in substitutions.iter().enumerate() {
+            if substitutions[..position].iter().any(|(other, _)| other == var) {
+                return Err(SubstitutionError::RepeatingVariable(var.0));
+            }
+        }
+        let mut answer = TypedPolynome::zero();
+        for monome in &self.monomes {
+            let mut kept = Vec::with_capacity(monome.vars.powers.len());
+            let mut replaced: Vec<(&TypedPolynome<T>, usize)> = Vec::new();
+            for &(index, power) in &monome.vars.powers {
+                match substitutions.iter().find(|(var, _)| var.0 == index) {
+                    Some((_, sub)) => replaced.push((sub, power)),
+                    None => kept.push((index, power)),
+                }
+            }
+            let mut term = TypedPolynome::from(TypedMonome {
+                coeff: monome.coeff.clone(),
+                vars: UntypedMonome { powers: kept },
+            });
+            for (sub, power) in replaced {
+                term *= sub.clone().pow(power);
+            }
+            answer += term;
+        }
+        answer.order();
+        *self = answer;
+        Ok(())
+    }
+
+    /// Applies several simultaneous polynome substitutions and returns the
+    /// result, the non-mutating counterpart of [`TypedPolynome::compose`].
+    ///
+    /// This is a change of variables for systems: every listed variable is
+    /// replaced by its polynome in one pass, never feeding one substitution
+    /// into another the way chained [`TypedPolynome::substitute_polynome`]
+    /// calls would. Listing the same variable twice fails with
+    /// [`SubstitutionError::RepeatingVariable`].
+    pub fn substitute_polynomes(
+        &self,
+        substitutions: Vec<(Var, TypedPolynome<T>)>,
+    ) -> Result<TypedPolynome<T>, SubstitutionError> {
+        let mut answer = self.clone();
+        answer.compose(substitutions)?;
+        Ok(answer)
+    }
+
+    /// Evaluates every partial derivative in `vars` at `point`, reusing
+    /// the point across components instead of materializing the symbolic
+    /// [`TypedPolynome::gradient`] first.
+    pub fn eval_gradient(
+        &self,
+        vars: &[Var],
+        point: &[(Var, T)],
+    ) -> Result<Vec<T>, SubstitutionError> {
+        let values = point.to_vec();
+        vars.iter()
+            .map(|&var| self.derivative(var).substitute(values.clone()))
+            .collect()
+    }
+
+    /// Precompiles the term structure once and returns a closure evaluating
+    /// the polynome at a point, for callers that evaluate the same
+    /// polynome many times; each invocation is plain arithmetic over a
+    /// flattened `(coeff, powers)` list.
+    ///
+    /// The closure checks values like [`TypedPolynome::substitute`]:
+    /// duplicate variables and variables missing from the point are
+    /// reported as errors, extra values are ignored.
+    pub fn into_fn(self) -> impl Fn(&[(Var, T)]) -> Result<T, SubstitutionError> {
+        let terms: Vec<(T, Vec<(usize, usize)>)> = self
+            .monomes
+            .into_iter()
+            .map(|monome| (monome.coeff, monome.vars.powers))
+            .collect();
+        move |values: &[(Var, T)]| {
+            for (position, (var, _)) in values.iter().enumerate() {
+                if values[..position].iter().any(|(other, _)| other == var) {
+                    return Err(SubstitutionError::RepeatingVariable(var.0));
+                }
+            }
+            let mut answer = T::zero();
+            for (coeff, powers) in &terms {
+                let mut term = coeff.clone();
+                for &(index, power) in powers {
+                    let value = values
+                        .iter()
+                        .find(|(var, _)| var.0 == index)
+                        .map(|(_, value)| value)
+                        .ok_or(SubstitutionError::MissingVariable(index))?;
+                    for _ in 0..power {
+                        term = term * value.clone();
+                    }
+                }
+                answer = answer + term;
+            }
+            Ok(answer)
+        }
+    }
+
+    /// Plugs a constant value in for `var` in place, keeping all other
+    /// variables symbolic.
+    ///
+    /// This is the fast path over `substitute_polynome(var, value.into())`:
+    /// each monome just has its coefficient multiplied by `value^power`,
+    /// with no polynome-power expansion. The result is ordered.
+    pub fn substitute_constant(&mut self, var: Var, value: T) {
+        for monome in &mut self.monomes {
+            let (power, rest) = monome.extract_variable(var);
+            let mut coeff = rest.coeff;
+            for _ in 0..power {
+                coeff = coeff * value.clone();
+            }
+            *monome = TypedMonome {
+                coeff,
+                vars: rest.vars,
+            };
+        }
+        self.order();
+    }
+
+    /// Substitutes the polynome `sub` for the variable `var`, leaving all
+    /// other variables in place.
+    pub fn substitute_polynome(&self, var: Var, sub: TypedPolynome<T>) -> TypedPolynome<T> {
+        let mut answer = TypedPolynome::zero();
+        for monome in &self.monomes {
+            let (power, rest) = monome.extract_variable(var);
+            if power == 0 {
+                answer.monomes.push(rest);
+            } else {
+                answer += sub.clone().pow(power) * rest;
+            }
+        }
+        answer
+    }
+
+    /// Returns the square-free decomposition of a univariate polynome over
+    /// a characteristic-zero field as `(factor, multiplicity)` pairs, using
+    /// Yun's algorithm on [`TypedPolynome::gcd`] and
+    /// [`TypedPolynome::div_rem`].
+    ///
+    /// Factors come out monic, in order of increasing multiplicity, with
+    /// constant factors dropped; a constant input yields no factors. Fails
+    /// with [`DivisionError::NotUnivariate`] when the polynome mentions a
+    /// variable other than `var`.
+    pub fn square_free(&self, var: Var) -> Result<Vec<(Self, usize)>, DivisionError>
+    where
+        T: Field,
+    {
+        let mut answer = Vec::new();
+        if self.is_constant() {
+            return Ok(answer);
+        }
+        let derivative = self.derivative(var);
+        let repeated = self.gcd(&derivative, var)?;
+        let mut square_free_part = self.div_rem(&repeated, var)?.0;
+        let mut difference =
+            derivative.div_rem(&repeated, var)?.0 - square_free_part.derivative(var);
+        let mut multiplicity = 1;
+        while !square_free_part.is_constant() {
+            let factor = square_free_part.gcd(&difference, var)?;
+            square_free_part = square_free_part.div_rem(&factor, var)?.0;
+            difference = difference.div_rem(&factor, var)?.0 - square_free_part.derivative(var);
+            if !factor.is_constant() {
+                answer.push((factor, multiplicity));
+            }
+            multiplicity += 1;
+        }
+        Ok(answer)
+    }
+
+    /// Multiplies two polynomes like `self * other` followed by
+    /// [`TypedPolynome::order`], but spreads the outer expansion loop
+    /// across threads with rayon and merges the partial products.
+    ///
+    /// A drop-in equivalent for degree-heavy expansions where the nested
+    /// loop dominates runtime; the result is identical to the sequential
+    /// product.
+    #[cfg(feature = "rayon")]
+    pub fn par_mul(&self, other: &Self) -> Self
+    where
+        T: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        let mut answer = TypedPolynome {
+            monomes: self
+                .monomes
+                .par_iter()
+                .flat_map_iter(|monome| {
+                    other
+                        .monomes
+                        .iter()
+                        .map(move |other| monome.clone() * other.clone())
+                })
+                .collect(),
+        };
+        answer.order();
+        answer
+    }
+
+    /// Multiplies two polynomes univariate in `var` with Karatsuba's
+    /// algorithm on their dense coefficient vectors, falling back to the
+    /// schoolbook product below a crossover length.
+    ///
+    /// A performance-only alternative for high-degree dense operands: the
+    /// result always equals `(self.clone() * other.clone()).ordered()`.
+    /// Fails with [`SubstitutionError::MissingVariable`] when either side
+    /// mentions another variable.
+    pub fn mul_karatsuba(&self, other: &Self, var: Var) -> Result<Self, SubstitutionError>
+    where
+        T: Field,
+    {
+        let a = self.to_coefficients(var)?;
+        let b = other.to_coefficients(var)?;
+        Ok(Self::from_coefficients(var, Self::karatsuba(&a, &b)))
+    }
+
+    /// Recursive Karatsuba product of dense coefficient vectors.
+    fn karatsuba(a: &[T], b: &[T]) -> Vec<T>
+    where
+        T: Field,
+    {
+        const CROSSOVER: usize = 32;
+
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        if a.len().min(b.len()) < CROSSOVER {
+            let mut answer = vec![T::zero(); a.len() + b.len() - 1];
+            for (offset, left) in a.iter().enumerate() {
+                for (position, right) in b.iter().enumerate() {
+                    answer[offset + position] =
+                        answer[offset + position].clone() + left.clone() * right.clone();
+                }
+            }
+            return answer;
+        }
+
+        let half = a.len().max(b.len()) / 2;
+        let (a_low, a_high) = a.split_at(half.min(a.len()));
+        let (b_low, b_high) = b.split_at(half.min(b.len()));
+        let low = Self::karatsuba(a_low, b_low);
+        let high = Self::karatsuba(a_high, b_high);
+        let a_sum: Vec<T> = (0..a_low.len().max(a_high.len()))
+            .map(|position| {
+                a_low.get(position).cloned().unwrap_or_else(T::zero)
+                    + a_high.get(position).cloned().unwrap_or_else(T::zero)
+            })
+            .collect();
+        let b_sum: Vec<T> = (0..b_low.len().max(b_high.len()))
+            .map(|position| {
+                b_low.get(position).cloned().unwrap_or_else(T::zero)
+                    + b_high.get(position).cloned().unwrap_or_else(T::zero)
+            })
+            .collect();
+        let mut middle = Self::karatsuba(&a_sum, &b_sum);
+        for (position, coeff) in middle.iter_mut().enumerate() {
+            *coeff = coeff.clone()
+                + -(low.get(position).cloned().unwrap_or_else(T::zero)
+                    + high.get(position).cloned().unwrap_or_else(T::zero));
+        }
+
+        let mut answer = vec![T::zero(); a.len() + b.len() - 1];
+        for (position, coeff) in low.into_iter().enumerate() {
+            answer[position] = answer[position].clone() + coeff;
+        }
+        for (position, coeff) in middle.into_iter().enumerate() {
+            answer[half + position] = answer[half + position].clone() + coeff;
+        }
+        for (position, coeff) in high.into_iter().enumerate() {
+            answer[2 * half + position] = answer[2 * half + position].clone() + coeff;
+        }
+        answer
+    }
+
+    /// Computes `self^exp mod modulus` for polynomes univariate in `var`
+    /// by square-and-multiply, reducing with [`TypedPolynome::div_rem`]
+    /// after every step so intermediate degrees stay below the modulus
+    /// degree.
+    ///
+    /// This is the kernel of distinct-degree factorization, where the
+    /// exponent is far too large for the naive `Pow`.
+    pub fn pow_mod(
+        &self,
+        mut exp: u64,
+        modulus: &TypedPolynome<T>,
+        var: Var,
+    ) -> Result<TypedPolynome<T>, DivisionError>
+    where
+        T: Field,
+    {
+        let mut base = self.div_rem(modulus, var)?.1;
+        let mut answer = TypedPolynome::one();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                answer = (answer * base.clone()).div_rem(modulus, var)?.1;
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = (base.clone() * base).div_rem(modulus, var)?.1;
+            }
+        }
+        Ok(answer)
+    }
+
+    /// Substitutes the polynome `inner` for `var` using Horner's scheme on
+    /// the coefficients of `self` in `var`, leaving other variables in
+    /// place as coefficients.
+    ///
+    /// For deep compositions this beats [`TypedPolynome::substitute_polynome`],
+    /// which raises `inner` to every occurring power separately.
+    pub fn compose_univariate(&self, var: Var, inner: &TypedPolynome<T>) -> TypedPolynome<T> {
+        let mut groups: Vec<TypedPolynome<T>> = Vec::new();
+        for monome in &self.monomes {
+            let (power, rest) = monome.extract_variable(var);
+            if groups.len() <= power {
+                groups.resize(power + 1, TypedPolynome::zero());
+            }
+            groups[power].monomes.push(rest);
+        }
+        let mut answer = TypedPolynome::zero();
+        for group in groups.into_iter().rev() {
+            answer *= inner.clone();
+            answer += group;
+        }
+        answer.order();
+        answer
+    }
+
+    /// Rewrites the polynome as a univariate in `var` whose coefficients
+    /// are polynomes in the remaining variables, returning
+    /// `(power of var, coefficient polynome)` pairs sorted by power with
+    /// vanished powers omitted.
+    ///
+    /// This recursive view treats `var` as the main variable, as
+    /// pseudo-division and resultants do. For `x^2*y + x*z + 1` with `X`
+    /// it yields `[(0, 1), (1, z), (2, y)]`.
+    pub fn as_univariate(&self, var: Var) -> Vec<(usize, TypedPolynome<T>)> {
+        let mut groups: BTreeMap<usize, TypedPolynome<T>> = BTreeMap::new();
+        for monome in &self.monomes {
+            let (power, rest) = monome.extract_variable(var);
+            groups
+                .entry(power)
+                .or_insert_with(TypedPolynome::zero)
+                .monomes
+                .push(rest);
+        }
+        for group in groups.values_mut() {
+            group.order();
+        }
+        groups
+            .into_iter()
+            .filter(|(_, group)| !group.monomes.is_empty())
+            .collect()
+    }
+
+    /// Returns the partial derivative with respect to `var`.
+    pub fn derivative(&self, var: Var) -> TypedPolynome<T> {
+        let mut monomes = Vec::new();
+        for monome in &self.monomes {
+            let (power, rest) = monome.extract_variable(var);
+            if power == 0 {
+                continue;
+            }
+            let mut factor = T::zero();
+            for _ in 0..power {
+                factor = factor + T::one();
+            }
+            let mut derived = rest;
+            derived.coeff = derived.coeff * factor;
+            if power > 1 {
+                derived.vars = derived.vars
+                    * UntypedMonome {
+                        powers: vec![(var.0, power - 1)],
+                    };
+            }
+            monomes.push(derived);
+        }
+        TypedPolynome { monomes }
+    }
+
+    /// Returns the maximal monome under the given monomial order, `None`
+    /// for the zero polynome. Works on a normalized copy, so un-combined
+    /// duplicates and zero terms cannot skew the result.
+    pub fn leading_term(&self, ord: MonomialOrder) -> Option<TypedMonome<T>> {
+        let mut normalized = self.clone();
+        normalized.order_by(ord);
+        normalized.monomes.pop()
+    }
+
+    /// Returns the coefficient of the [`leading_term`] under the given
+    /// monomial order, `None` for the zero polynome.
+    ///
+    /// [`leading_term`]: TypedPolynome::leading_term
+    pub fn leading_coefficient(&self, ord: MonomialOrder) -> Option<T> {
+        self.leading_term(ord).map(|monome| monome.coeff)
+    }
+
+    /// Returns the first `order + 1` Taylor coefficients of the polynome
+    /// around `at`, i.e. `f^(k)(at) / k!` for `k = 0..=order`, by repeated
+    /// [`TypedPolynome::derivative`] and [`TypedPolynome::substitute`].
+    ///
+    /// The polynome must be univariate in `var` for the substitution to
+    /// succeed. For `x^2` around `1` with order 2 this yields `[1, 2, 1]`.
+    pub fn taylor_coefficients(
+        &self,
+        var: Var,
+        at: T,
+        order: usize,
+    ) -> Result<Vec<T>, SubstitutionError>
+    where
+        T: Field,
+    {
+        let mut answer = Vec::with_capacity(order + 1);
+        let mut current = self.clone();
+        let mut factorial = T::one();
+        for k in 0..=order {
+            if k > 0 {
+                let mut factor = T::zero();
+                for _ in 0..k {
+                    factor = factor + T::one();
+                }
+                factorial = factorial * factor;
+            }
+            answer.push(current.substitute(vec![(var, at.clone())])? / factorial.clone());
+            current = current.derivative(var);
+        }
+        Ok(answer)
+    }
+
+    /// Returns the antiderivative with respect to `var`, with integration
+    /// constant zero. Each monome's power of `var` is raised by one and its
+    /// coefficient divided by the new power; terms without `var` gain a
+    /// factor of `var`.
+    pub fn integrate(&self, var: Var) -> TypedPolynome<T>
+    where
+        T: Field,
+    {
+        let mut monomes = Vec::with_capacity(self.monomes.len());
+        for monome in &self.monomes {
+            let (power, rest) = monome.extract_variable(var);
+            let mut divisor = T::zero();
+            for _ in 0..power + 1 {
+                divisor = divisor + T::one();
+            }
+            let mut integrated = rest;
+            integrated.coeff = integrated.coeff / divisor;
+            integrated.vars = integrated.vars
+                * UntypedMonome {
+                    powers: vec![(var.0, power + 1)],
+                };
+            monomes.push(integrated);
+        }
+        TypedPolynome { monomes }
+    }
+
+    /// Returns the partial derivatives with respect to each variable in
+    /// `vars`, in order.
+    pub fn gradient(&self, vars: &[Var]) -> Vec<TypedPolynome<T>> {
+        vars.iter().map(|&var| self.derivative(var)).collect()
+    }
+
+    /// Splits the polynome into its homogeneous components, keyed by total
+    /// degree. Each component is returned in ordered form; degrees whose
+    /// component cancels to zero are absent from the map.
+    pub fn homogeneous_decomposition(&self) -> BTreeMap<usize, TypedPolynome<T>> {
+        let mut parts: BTreeMap<usize, TypedPolynome<T>> = BTreeMap::new();
+        for monome in &self.monomes {
+            parts
+                .entry(monome.degree())
+                .or_insert_with(TypedPolynome::zero)
+                .monomes
+                .push(monome.clone());
+        }
+        for part in parts.values_mut() {
+            part.order();
+        }
+        parts.retain(|_, part| !part.monomes.is_empty());
+        parts
+    }
+
+    /// Returns the homogeneous components as a vec indexed by total degree,
+    /// padding degrees whose component cancels to zero with empty
+    /// polynomes up to the maximum occurring degree.
+    ///
+    /// The dense counterpart of [`TypedPolynome::homogeneous_decomposition`];
+    /// the zero polynome yields an empty vec.
+    pub fn homogeneous_components(&self) -> Vec<TypedPolynome<T>> {
+        let parts = self.homogeneous_decomposition();
+        let Some(max_degree) = parts.keys().next_back().copied() else {
+            return Vec::new();
+        };
+        let mut components = vec![TypedPolynome::zero(); max_degree + 1];
+        for (degree, part) in parts {
+            components[degree] = part;
+        }
+        components
+    }
+
+    /// Raises the polynome to a power like [`Pow`] without consuming the
+    /// base, so `base.pow_ref(k)` works inside a loop over exponents. The
+    /// single clone happens here rather than at every call site.
+    pub fn pow_ref(&self, exp: usize) -> TypedPolynome<T> {
+        self.clone().pow(exp)
+    }
+
+    /// Raises the polynome to a power like [`Pow`], but fails with
+    /// [`ExpansionError::TooManyTerms`] as soon as any intermediate ordered
+    /// result holds more than `max_terms` monomes.
+    ///
+    /// This is the production-safe power for untrusted exponents: memory
+    /// stays bounded by `max_terms` ordered terms per intermediate.
+    pub fn pow_bounded(&self, mut exp: usize, max_terms: usize) -> Result<Self, ExpansionError> {
+        let mut base = self.clone();
+        base.order();
+        let mut answer = TypedPolynome::one();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                answer *= base.clone();
+                answer.order();
+                if answer.monomes.len() > max_terms {
+                    return Err(ExpansionError::TooManyTerms);
+                }
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = base.clone() * base;
+                base.order();
+                if base.monomes.len() > max_terms {
+                    return Err(ExpansionError::TooManyTerms);
+                }
+            }
+        }
+        Ok(answer)
+    }
+
+    /// Returns the top homogeneous component: the sum of all terms of
+    /// maximal total degree. The leading form of the zero polynome is zero.
+    pub fn leading_form(&self) -> TypedPolynome<T> {
+        self.homogeneous_decomposition()
+            .into_iter()
+            .next_back()
+            .map(|(_, part)| part)
+            .unwrap_or_else(TypedPolynome::zero)
+    }
+}
+
+impl<T: CommutativeSemiring + fmt::Display> TypedPolynome<T> {
+    /// Renders the polynome as LaTeX, e.g. `2x^{2}y + 3`.
+    ///
+    /// Variables take their name from `names` by index, falling back to
+    /// `x_{i}` past the end of the slice. Unit coefficients are hidden on
+    /// non-constant terms, negative terms are joined with ` - ` instead of
+    /// ` + `, and the terms are put through [`TypedPolynome::order`] first
+    /// so the output is deterministic.
+    pub fn to_latex(&self, names: &[&str]) -> String {
+        let mut ordered = self.clone();
+        ordered.order();
+        if ordered.monomes.is_empty() {
+            return "0".to_string();
+        }
+        let mut output = String::new();
+        for (position, monome) in ordered.monomes.iter().enumerate() {
+            let mut term = String::new();
+            let coeff = monome.coeff.to_string();
+            if monome.vars.powers.is_empty() {
+                term.push_str(&coeff);
+            } else {
+                match coeff.as_str() {
+                    "1" => {}
+                    "-1" => term.push('-'),
+                    _ => term.push_str(&coeff),
+                }
+                for &(index, power) in &monome.vars.powers {
+                    match names.get(index) {
+                        Some(name) => term.push_str(name),
+                        None => term.push_str(&format!("x_{{{}}}", index)),
+                    }
+                    if power > 1 {
+                        term.push_str(&format!("^{{{}}}", power));
+                    }
+                }
+            }
+            match (position, term.strip_prefix('-')) {
+                (0, _) => output.push_str(&term),
+                (_, Some(negated)) => {
+                    output.push_str(" - ");
+                    output.push_str(negated);
+                }
+                (_, None) => {
+                    output.push_str(" + ");
+                    output.push_str(&term);
+                }
+            }
+        }
+        output
+    }
+}
+
+impl TypedPolynome<i64> {
+    /// Returns the content: the greatest common divisor of all coefficients
+    /// of the normalized form, and `0` for the zero polynome.
+    ///
+    /// A single-term polynome's content is the absolute value of its
+    /// coefficient.
+    pub fn content(&self) -> i64 {
+        fn gcd(mut a: i64, mut b: i64) -> i64 {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a.abs()
+        }
+
+        self.normalized()
+            .monomes
+            .iter()
+            .fold(0, |answer, monome| gcd(answer, monome.coeff))
+    }
+
+    /// Reduces every coefficient into `0..modulus` with `rem_euclid` and
+    /// normalizes, dropping terms that vanish modulo `modulus`.
+    pub fn reduce_mod(&self, modulus: i64) -> TypedPolynome<i64> {
+        let mut answer = TypedPolynome {
+            monomes: self
+                .monomes
+                .iter()
+                .map(|monome| TypedMonome {
+                    coeff: monome.coeff.rem_euclid(modulus),
+                    vars: monome.vars.clone(),
+                })
+                .collect(),
+        };
+        answer.order();
+        answer
+    }
+
+    /// Evaluates the polynome over the prime field `F_p`, reducing after
+    /// every multiply with `i128` intermediates so no high-degree term can
+    /// overflow — unlike evaluating over `i64` and reducing at the end.
+    ///
+    /// The result lies in `0..p`. Value checks match
+    /// [`TypedPolynome::substitute`]: duplicates and missing variables are
+    /// errors, extra values are ignored.
+    pub fn eval_mod_p(&self, values: &[(Var, i64)], p: i64) -> Result<i64, SubstitutionError> {
+        for (position, (var, _)) in values.iter().enumerate() {
+            if values[..position].iter().any(|(other, _)| other == var) {
+                return Err(SubstitutionError::RepeatingVariable(var.0));
+            }
+        }
+        let mut answer: i64 = 0;
+        for monome in &self.monomes {
+            let mut term = monome.coeff.rem_euclid(p);
+            for &(index, power) in &monome.vars.powers {
+                let value = values
+                    .iter()
+                    .find(|(var, _)| var.0 == index)
+                    .map(|(_, value)| value.rem_euclid(p))
+                    .ok_or(SubstitutionError::MissingVariable(index))?;
+                for _ in 0..power {
+                    term = (term as i128 * value as i128).rem_euclid(p as i128) as i64;
+                }
+            }
+            answer = (answer + term).rem_euclid(p);
+        }
+        Ok(answer)
+    }
+
+    /// Probabilistically tests whether the polynome is identically zero by
+    /// evaluating at a uniformly random point modulo `field_size`
+    /// (Schwartz–Zippel), which is far cheaper than expanding and ordering
+    /// a huge product.
+    ///
+    /// `field_size` should be prime. A non-zero polynome of total degree
+    /// `d` passes the test with probability at most `d / field_size` per
+    /// call, so repeat the call or enlarge the field to taste; `true` from
+    /// the zero polynome is always correct. Evaluation goes through
+    /// [`TypedPolynome::eval_mod_p`], reducing after every multiply, so
+    /// arbitrarily high degrees cannot overflow.
+    #[cfg(feature = "rand")]
+    pub fn is_probably_zero(&self, rng: &mut impl rand::Rng, field_size: i64) -> bool {
+        let reduced = self.reduce_mod(field_size);
+        let values: Vec<(Var, i64)> = reduced
+            .variables()
+            .into_iter()
+            .map(|var| (var, rng.gen_range(0..field_size)))
+            .collect();
+        reduced
+            .eval_mod_p(&values, field_size)
+            .expect("every variable was assigned a coordinate")
+            == 0
+    }
+
+    /// Divides through by the [`TypedPolynome::content`], with the sign
+    /// chosen so the leading coefficient under the default order is
+    /// positive. The zero polynome is its own primitive part.
+    pub fn primitive_part(&self) -> TypedPolynome<i64> {
+        let mut answer = self.normalized();
+        let mut content = self.content();
+        if content == 0 {
+            return answer;
+        }
+        if answer
+            .leading_coefficient(MonomialOrder::Lex)
+            .is_some_and(|leading| leading < 0)
+        {
+            content = -content;
+        }
+        for monome in &mut answer.monomes {
+            monome.coeff /= content;
+        }
+        answer
+    }
+}
+
+impl TypedPolynome<f64> {
+    /// Checks that every coefficient is finite, returning the index of the
+    /// first `NaN` or infinite one in `monomes`.
+    ///
+    /// Call this at the boundary when coefficients come out of an upstream
+    /// numeric computation: a non-finite coefficient never merges or
+    /// cancels sensibly, and `NaN` compares unequal even to itself, which
+    /// poisons `==` on the whole polynome. [`TypedPolynome::order`] itself
+    /// stays safe regardless — it sorts by the variable part alone and
+    /// never compares coefficients.
+    pub fn validate_finite(&self) -> Result<(), usize> {
+        match self
+            .monomes
+            .iter()
+            .position(|monome| !monome.coeff.is_finite())
+        {
+            Some(position) => Err(position),
+            None => Ok(()),
+        }
+    }
+
+    /// Compares two float polynomes coefficient-wise within `tol` after
+    /// normalizing both, treating terms whose coefficient is smaller than
+    /// `tol` in absolute value as absent.
+    ///
+    /// Use this instead of `==` in float-based tests: expansion introduces
+    /// rounding noise, and an exactly-zero term on one side may survive as
+    /// a tiny residue on the other.
+    pub fn approx_eq(&self, other: &TypedPolynome<f64>, tol: f64) -> bool {
+        let keep = |polynome: &TypedPolynome<f64>| {
+            let mut normalized = polynome.normalized();
+            normalized.monomes.retain(|monome| monome.coeff.abs() >= tol);
+            normalized
+        };
+        let left = keep(self);
+        let right = keep(other);
+        left.monomes.len() == right.monomes.len()
+            && left
+                .monomes
+                .iter()
+                .zip(&right.monomes)
+                .all(|(a, b)| a.vars == b.vars && (a.coeff - b.coeff).abs() <= tol)
+    }
+
+    /// Searches for a root of a univariate polynome in `var` with Newton's
+    /// method starting from `initial`.
+    ///
+    /// Returns `Some(x)` once `|f(x)| < tol` within `max_iters` iterations,
+    /// and `None` when the iteration fails to converge, hits a zero
+    /// derivative, or the polynome mentions a variable other than `var`.
+    pub fn find_root_newton(
+        &self,
+        var: Var,
+        initial: f64,
+        max_iters: usize,
+        tol: f64,
+    ) -> Option<f64> {
+        let derivative = self.derivative(var);
+        let mut x = initial;
+        for _ in 0..max_iters {
+            let value = self.substitute(vec![(var, x)]).ok()?;
+            if value.abs() < tol {
+                return Some(x);
+            }
+            let slope = derivative.substitute(vec![(var, x)]).ok()?;
+            if slope == 0.0 {
+                return None;
+            }
+            x -= value / slope;
+        }
+        None
+    }
+}
+
+impl TypedPolynome<num_rational::Ratio<i64>> {
+    /// Returns a copy with every coefficient reduced to lowest terms and
+    /// zero terms dropped.
+    ///
+    /// `Ratio` normally reduces on construction, but coefficients built
+    /// through `new_raw` or custom arithmetic may arrive unreduced.
+    pub fn reduce_fractions(&self) -> TypedPolynome<num_rational::Ratio<i64>> {
+        let mut answer = TypedPolynome {
+            monomes: self
+                .monomes
+                .iter()
+                .map(|monome| TypedMonome {
+                    coeff: num_rational::Ratio::new(*monome.coeff.numer(), *monome.coeff.denom()),
+                    vars: monome.vars.clone(),
+                })
+                .collect(),
+        };
+        answer.order();
+        answer
+    }
+}
+
+/// Accumulates `(coefficient, variable powers)` terms into a
+/// [`TypedPolynome`], a convenient front for programmatic generation and
+/// translation from external sparse formats.
+///
+/// ```
+/// use rust_polynomes::variables::{X, Y};
+/// use rust_polynomes::{TypedPolynome, TypedPolynomeBuilder};
+///
+/// let mut builder = TypedPolynomeBuilder::new();
+/// builder.term(2u32, &[(X, 1), (Y, 1)]).term(3u32, &[]);
+/// let polynome: TypedPolynome<u32> = builder.build();
+/// assert_eq!(polynome.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TypedPolynomeBuilder<T: CommutativeSemiring> {
+    monomes: Vec<TypedMonome<T>>,
+}
+
+impl<T: CommutativeSemiring> TypedPolynomeBuilder<T> {
+    /// Creates a builder with no terms; [`TypedPolynomeBuilder::build`] on
+    /// it yields [`TypedPolynome::zero`].
+    pub fn new() -> Self {
+        Self {
+            monomes: Vec::new(),
+        }
+    }
+
+    /// Appends one term. Repeated variables within `vars` have their powers
+    /// summed and zero powers are dropped, so any listing order is
+    /// accepted.
+    pub fn term(&mut self, coeff: T, vars: &[(Var, usize)]) -> &mut Self {
+        let vars = UntypedMonome {
+            powers: vars.iter().map(|&(var, power)| (var.0, power)).collect(),
+        }
+        .normalized();
+        self.monomes.push(TypedMonome { coeff, vars });
+        self
+    }
+
+    /// Finishes the polynome, merging like terms and dropping zero
+    /// coefficients via [`TypedPolynome::order`].
+    pub fn build(self) -> TypedPolynome<T> {
+        let mut answer = TypedPolynome {
+            monomes: self.monomes,
+        };
+        answer.order();
+        answer
+    }
+}
+
+/// Builds the unique minimal-degree polynome univariate in `var` passing
+/// through the given `(x, y)` points, by Lagrange interpolation over field
+/// coefficients.
+///
+/// Returns `None` when two points share an x coordinate. An empty slice
+/// interpolates to the zero polynome. The inverse of evaluating with
+/// [`TypedPolynome::substitute`] at the same points.
+pub fn interpolate<T: Field + PartialEq>(points: &[(T, T)], var: Var) -> Option<TypedPolynome<T>> {
+    for (position, (x, _)) in points.iter().enumerate() {
+        if points[..position].iter().any(|(other, _)| other == x) {
+            return None;
+        }
+    }
+    let mut answer = TypedPolynome::zero();
+    for (position, (x, y)) in points.iter().enumerate() {
+        let mut numerator = TypedPolynome::one();
+        let mut denominator = T::one();
+        for (other_position, (other_x, _)) in points.iter().enumerate() {
+            if other_position == position {
+                continue;
+            }
+            numerator *= TypedPolynome::from(Coeff(T::one()) * var)
+                + Coeff(-other_x.clone());
+            denominator = denominator * (x.clone() + -other_x.clone());
+        }
+        answer += numerator
+            .div_scalar(denominator)
+            .expect("distinct points give non-zero denominators")
+            .scale(y.clone());
+    }
+    answer.order();
+    Some(answer)
+}
+
+/// Generates a random normalized polynome with at most `max_terms` terms
+/// over the variables `x_0` to `x_{num_vars - 1}`, each term of total
+/// degree at most `max_degree` and with its coefficient drawn from
+/// `coeff`.
+///
+/// The output is guaranteed to be in [`TypedPolynome::order`]-ed form, so
+/// downstream property tests can rely on the crate's invariants; note that
+/// merging like terms may leave fewer than `max_terms` monomes.
+#[cfg(feature = "rand")]
+pub fn random_polynome<T, R>(
+    rng: &mut R,
+    num_vars: usize,
+    max_degree: usize,
+    max_terms: usize,
+    mut coeff: impl FnMut(&mut R) -> T,
+) -> TypedPolynome<T>
+where
+    T: CommutativeSemiring,
+    R: rand::Rng + ?Sized,
+{
+    let mut answer = TypedPolynome::zero();
+    for _ in 0..max_terms {
+        let degree = rng.gen_range(0..=max_degree);
+        let mut vars = UntypedMonome::default();
+        if num_vars > 0 {
+            for _ in 0..degree {
+                vars = vars
+                    * UntypedMonome {
+                        powers: vec![(rng.gen_range(0..num_vars), 1)],
+                    };
+            }
+        }
+        answer.monomes.push(TypedMonome {
+            coeff: coeff(rng),
+            vars,
+        });
+    }
+    answer.order();
+    answer
+}
+
+/// Returns the Jacobian matrix of a system of polynomes: entry `[i][j]` is
+/// the partial derivative of `system[i]` with respect to `vars[j]`.
+///
+/// An empty system yields an empty matrix; empty `vars` yield one empty row
+/// per polynome.
+pub fn jacobian<T: CommutativeSemiring>(
+    system: &[TypedPolynome<T>],
+    vars: &[Var],
+) -> Vec<Vec<TypedPolynome<T>>> {
+    system.iter().map(|polynome| polynome.gradient(vars)).collect()
+}
+
+impl<T: CommutativeSemiring> From<TypedMonome<T>> for TypedPolynome<T> {
+    fn from(monome: TypedMonome<T>) -> Self {
+        Self {
+            monomes: vec![monome],
+        }
+    }
+}
+
+impl<T: CommutativeSemiring> From<Coeff<T>> for TypedPolynome<T> {
+    fn from(coeff: Coeff<T>) -> Self {
+        Self {
+            monomes: vec![coeff.into()],
+        }
+    }
+}
+
+impl<T: CommutativeSemiring, const N: usize> From<[TypedMonome<T>; N]> for TypedPolynome<T> {
+    /// Collects a literal term list into normalized form via
+    /// [`TypedPolynome::order`].
+    fn from(monomes: [TypedMonome<T>; N]) -> Self {
+        TypedPolynome {
+            monomes: monomes.into(),
+        }
+        .ordered()
+    }
+}
+
+impl<T: CommutativeSemiring> From<Vec<TypedMonome<T>>> for TypedPolynome<T> {
+    /// Collects a term list into normalized form via
+    /// [`TypedPolynome::order`].
+    fn from(monomes: Vec<TypedMonome<T>>) -> Self {
+        TypedPolynome { monomes }.ordered()
+    }
+}
+
+impl<T: CommutativeSemiring, const N: usize> From<[(T, &[(Var, usize)]); N]>
+    for TypedPolynome<T>
+{
+    /// Builds a polynome from `(coefficient, exponents)` rows, the
+    /// table-driven sibling of [`TypedPolynomeBuilder`]; the result is
+    /// normalized.
+    fn from(terms: [(T, &[(Var, usize)]); N]) -> Self {
+        let mut builder = TypedPolynomeBuilder::new();
+        for (coeff, vars) in terms {
+            builder.term(coeff, vars);
+        }
+        builder.build()
+    }
+}
+
+impl<T: CommutativeSemiring, U: Into<UntypedPolynome>> From<U> for TypedPolynome<T> {
+    /// Attaches the unit coefficient to every monome of an untyped polynome.
+    fn from(polynome: U) -> Self {
+        let polynome: UntypedPolynome = polynome.into();
+        Self {
+            monomes: polynome
+                .monomes
+                .into_iter()
+                .map(TypedMonome::from)
+                .collect(),
+        }
+    }
+}
+
+/// Lets a bare numeric literal become a constant polynome, so
+/// `let p: TypedPolynome<i32> = 5.into();` works without the [`Coeff`]
+/// wrapper.
+///
+/// A blanket `From<U: CommutativeSemiring>` would conflict with the
+/// untyped conversion above, so the impls are spelled out per numeric
+/// type instead.
+macro_rules! impl_from_constant {
+    ($($t:ty),*) => {$(
+        impl From<$t> for TypedPolynome<$t> {
+            fn from(value: $t) -> Self {
+                TypedPolynome::from(Coeff(value))
+            }
+        }
+    )*};
+}
+
+impl_from_constant!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T: CommutativeSemiring, U: Into<TypedPolynome<T>>> Add<U> for TypedPolynome<T> {
+    type Output = TypedPolynome<T>;
+
+    fn add(mut self, rhs: U) -> TypedPolynome<T> {
+        self.monomes.extend(rhs.into().monomes);
+        self
+    }
+}
+
+impl<T: CommutativeSemiring, U: Into<TypedPolynome<T>>> AddAssign<U> for TypedPolynome<T> {
+    /// Appends the monomes of `rhs` in place, leaving the result in the
+    /// same unordered form [`Add`] produces.
+    fn add_assign(&mut self, rhs: U) {
+        self.monomes.extend(rhs.into().monomes);
+    }
+}
+
+impl<T: CommutativeSemiring + Neg<Output = T>, U: Into<TypedPolynome<T>>> SubAssign<U>
+    for TypedPolynome<T>
+{
+    /// Appends the negated monomes of `rhs` in place, matching [`Sub`].
+    fn sub_assign(&mut self, rhs: U) {
+        self.monomes.extend((-rhs.into()).monomes);
+    }
+}
+
+impl<T: CommutativeSemiring> AddAssign<&TypedPolynome<T>> for TypedPolynome<T> {
+    /// The borrowed counterpart of `AddAssign`, cloning internally so
+    /// accumulation loops need no `.clone()` at the call site.
+    fn add_assign(&mut self, rhs: &TypedPolynome<T>) {
+        self.monomes.extend(rhs.monomes.iter().cloned());
+    }
+}
+
+impl<T: CommutativeSemiring> MulAssign<&TypedPolynome<T>> for TypedPolynome<T> {
+    /// The borrowed counterpart of `MulAssign`; behavior matches the owned
+    /// version exactly.
+    fn mul_assign(&mut self, rhs: &TypedPolynome<T>) {
+        *self *= rhs.clone();
+    }
+}
+
+impl<T: CommutativeSemiring, U: Into<TypedPolynome<T>>> MulAssign<U> for TypedPolynome<T> {
+    /// Replaces `self` with the product, in the ordered form [`Mul`] produces.
+    fn mul_assign(&mut self, rhs: U) {
+        let lhs = std::mem::replace(self, TypedPolynome::zero());
+        *self = lhs * rhs;
+    }
+}
+
+impl<T: CommutativeSemiring + Neg<Output = T>> Neg for TypedPolynome<T> {
+    type Output = TypedPolynome<T>;
+
+    fn neg(mut self) -> TypedPolynome<T> {
+        self.negate_in_place();
+        self
+    }
+}
+
+impl<T: CommutativeSemiring + Neg<Output = T>, U: Into<TypedPolynome<T>>> Sub<U>
+    for TypedPolynome<T>
+{
+    type Output = TypedPolynome<T>;
+
+    // Subtraction really is addition of the negated operand; negating in
+    // place just avoids the temporary `Neg` allocation.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: U) -> TypedPolynome<T> {
+        let mut rhs = rhs.into();
+        rhs.negate_in_place();
+        self + rhs
+    }
+}
+
+impl<T: CommutativeSemiring, U: Into<TypedPolynome<T>>> Mul<U> for TypedPolynome<T> {
+    type Output = TypedPolynome<T>;
+
+    /// Multiplies the two monome lists pairwise and returns the product in
+    /// canonical ordered form, with like terms merged and zeros dropped.
+    ///
+    /// The exact `monomes` vec of the result is part of the contract:
+    /// assertions may rely on it being the [`TypedPolynome::order`] of the
+    /// outer-loop-over-`self`, inner-loop-over-`rhs` expansion.
+    fn mul(self, rhs: U) -> TypedPolynome<T> {
+        let rhs: TypedPolynome<T> = rhs.into();
+        let mut answer = TypedPolynome {
+            monomes: self
+                .monomes
+                .into_iter()
+                .flat_map(|monome| {
+                    rhs.monomes
+                        .iter()
+                        .map(move |other| monome.clone() * other.clone())
+                })
+                .collect(),
+        };
+        answer.order();
+        answer
+    }
+}
+
+impl<T: CommutativeSemiring> Pow<usize> for TypedPolynome<T> {
+    type Output = TypedPolynome<T>;
+
+    /// Raises the polynome to a power by binary exponentiation, ordering
+    /// after every multiplication so like terms collapse along the way.
+    fn pow(self, mut pow: usize) -> TypedPolynome<T> {
+        if pow == 0 {
+            return TypedPolynome::one();
+        }
+        let mut base = self;
+        let mut answer = TypedPolynome::one();
+        while pow > 0 {
+            if pow % 2 == 1 {
+                answer *= base.clone();
+                answer.order();
+            }
+            pow /= 2;
+            if pow > 0 {
+                base = base.clone() * base;
+                base.order();
+            }
+        }
+        answer
+    }
+}
+
+impl<T: CommutativeSemiring + Hash> Hash for TypedPolynome<T> {
+    /// Hashes the [`TypedPolynome::normalized`] view, so polynomes that are
+    /// equal up to ordering and un-combined duplicates hash identically.
+    ///
+    /// Note the deliberate mismatch with the derived, structural `Eq`:
+    /// equal polynomes always hash equal, but so do some structurally
+    /// unequal ones. Normalize before inserting into a map when structural
+    /// differences should not create separate keys.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for monome in &self.normalized().monomes {
+            monome.coeff.hash(state);
+            monome.vars.hash(state);
+        }
+    }
+}
+
+impl<T: CommutativeSemiring> IntoIterator for TypedPolynome<T> {
+    type Item = TypedMonome<T>;
+    type IntoIter = std::vec::IntoIter<TypedMonome<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.monomes.into_iter()
+    }
+}
+
+impl<'a, T: CommutativeSemiring> IntoIterator for &'a TypedPolynome<T> {
+    type Item = &'a TypedMonome<T>;
+    type IntoIter = std::slice::Iter<'a, TypedMonome<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.monomes.iter()
+    }
+}
+
+impl<T: CommutativeSemiring> FromIterator<TypedMonome<T>> for TypedPolynome<T> {
+    /// Collects terms as they come; call [`TypedPolynome::order`] on the
+    /// result to normalize, mirroring the behavior of `Add`.
+    fn from_iter<I: IntoIterator<Item = TypedMonome<T>>>(iter: I) -> Self {
+        Self {
+            monomes: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: CommutativeSemiring> Sum for TypedPolynome<T> {
+    /// Sums polynomes starting from [`TypedPolynome::zero`].
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |answer, polynome| answer + polynome)
+    }
+}
+
+impl<T: CommutativeSemiring> Sum<TypedMonome<T>> for TypedPolynome<T> {
+    /// Sums individual terms into a polynome.
+    fn sum<I: Iterator<Item = TypedMonome<T>>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |answer, monome| answer + monome)
+    }
+}
+
+impl<T: CommutativeSemiring> Product for TypedPolynome<T> {
+    /// Multiplies polynomes starting from [`TypedPolynome::one`].
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |answer, polynome| answer * polynome)
+    }
+}
+
+impl<T: CommutativeSemiring + fmt::Display> fmt::Display for TypedPolynome<T> {
+    /// Prints the terms joined by ` + ` on one line; with the `#` alternate
+    /// flag, prints one term per line with aligned `+`/`-` signs instead,
+    /// which diffs much better for large polynomes:
+    ///
+    /// ```text
+    ///   2*x_0^2
+    /// + 3*x_1
+    /// - 1
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.monomes.is_empty() {
+            return write!(f, "0");
+        }
+        for (position, monome) in self.monomes.iter().enumerate() {
+            if f.alternate() {
+                let term = monome.to_string();
+                match (position, term.strip_prefix('-')) {
+                    (0, _) => write!(f, "  {}", term)?,
+                    (_, Some(negated)) => write!(f, "\n- {}", negated)?,
+                    (_, None) => write!(f, "\n+ {}", term)?,
+                }
+            } else {
+                if position > 0 {
+                    write!(f, " + ")?;
+                }
+                write!(f, "{}", monome)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,244 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Mul;
+
+use num_traits::Pow;
+
+use crate::typed_monome::TypedMonome;
+use crate::typed_polynome::TypedPolynome;
+use crate::variables::Var;
+
+/// A coefficient-free product of variables raised to powers.
+///
+/// `powers` holds `(variable index, power)` pairs. Every operation of the
+/// crate keeps the list sorted by index, without repetitions and without
+/// zero powers; the empty list is the unit monome `1`.
+///
+/// ```
+/// use rust_polynomes::variables::{X, Y};
+/// use rust_polynomes::UntypedMonome;
+///
+/// let monome: UntypedMonome = X * Y * X;
+/// assert_eq!(monome.powers, vec![(0, 2), (1, 1)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct UntypedMonome {
+    pub powers: Vec<(usize, usize)>,
+}
+
+impl UntypedMonome {
+    /// Builds a monome from raw `(variable index, power)` pairs, sorting
+    /// them, merging duplicate indices by summing and dropping zero powers.
+    ///
+    /// This is the only safe entry point for externally supplied pairs: a
+    /// literal `UntypedMonome { powers }` skips the sorted non-repeating
+    /// invariant and can silently compare unequal to its canonical form.
+    pub fn from_powers(pairs: Vec<(usize, usize)>) -> Self {
+        UntypedMonome { powers: pairs }.normalized()
+    }
+
+    /// Returns the total degree, the sum of all powers.
+    pub fn degree(&self) -> usize {
+        self.powers.iter().map(|&(_, power)| power).sum()
+    }
+
+    /// Returns whether `var` occurs in the monome with a non-zero power.
+    pub fn contains_variable(&self, var: Var) -> bool {
+        self.powers
+            .iter()
+            .any(|&(index, power)| index == var.0 && power > 0)
+    }
+
+    /// Rewrites every variable through `f`, restoring the sorted
+    /// non-repeating invariant afterwards. Two variables mapped to the same
+    /// target have their powers added.
+    pub fn remap_variables(&mut self, f: impl Fn(Var) -> Var) {
+        let mut powers: Vec<(usize, usize)> = self
+            .powers
+            .iter()
+            .map(|&(index, power)| (f(Var(index)).0, power))
+            .collect();
+        powers.sort_unstable();
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(powers.len());
+        for (index, power) in powers {
+            match merged.last_mut() {
+                Some(last) if last.0 == index => last.1 += power,
+                _ => merged.push((index, power)),
+            }
+        }
+        self.powers = merged;
+    }
+
+    /// Renames a single variable, a shorthand over [`remap_variables`].
+    ///
+    /// [`remap_variables`]: UntypedMonome::remap_variables
+    pub fn rename_variable(&mut self, from: Var, to: Var) {
+        self.remap_variables(|var| if var == from { to } else { var });
+    }
+
+    /// Returns a copy with the powers sorted, duplicate indices merged and
+    /// zero powers dropped, regardless of how the monome was built.
+    pub(crate) fn normalized(&self) -> UntypedMonome {
+        let mut monome = self.clone();
+        monome.canonicalize();
+        monome
+    }
+
+    /// Restores the sorted non-repeating invariant in place, merging
+    /// duplicate indices by summing and stripping zero powers, so a monome
+    /// built from external data compares equal to its clean form.
+    pub fn canonicalize(&mut self) {
+        self.powers.sort_unstable();
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(self.powers.len());
+        for (index, power) in self.powers.drain(..) {
+            match merged.last_mut() {
+                Some(last) if last.0 == index => last.1 += power,
+                _ => merged.push((index, power)),
+            }
+        }
+        merged.retain(|&(_, power)| power > 0);
+        self.powers = merged;
+    }
+
+    /// Returns the power of `var` in the monome, zero if it does not occur.
+    pub fn degree_in(&self, var: Var) -> usize {
+        self.powers
+            .iter()
+            .find(|&&(index, _)| index == var.0)
+            .map(|&(_, power)| power)
+            .unwrap_or(0)
+    }
+
+    /// Iterates over the `(variable, power)` pairs in index order without
+    /// cloning, shielding callers from the concrete `powers` layout.
+    pub fn iter_powers(&self) -> impl Iterator<Item = (Var, usize)> + '_ {
+        self.powers.iter().map(|&(index, power)| (Var(index), power))
+    }
+
+    /// Raises the monome to a power like [`Pow`] without consuming it; see
+    /// [`TypedPolynome::pow_ref`].
+    ///
+    /// [`TypedPolynome::pow_ref`]: crate::TypedPolynome::pow_ref
+    pub fn pow_ref(&self, exp: usize) -> UntypedMonome {
+        self.clone().pow(exp)
+    }
+
+    /// Differentiates the monome with respect to `var`.
+    ///
+    /// An untyped monome has no coefficient to hold the power brought down
+    /// by the product rule, so the result is typed over `i64`: `x^3` maps
+    /// to `3*x^2` and a monome without `var` to the zero polynome.
+    pub fn derivative(&self, var: Var) -> TypedPolynome<i64> {
+        let power = self.degree_in(var);
+        if power == 0 {
+            return TypedPolynome::zero();
+        }
+        let vars = UntypedMonome {
+            powers: self
+                .powers
+                .iter()
+                .filter(|&&(index, _)| index != var.0)
+                .copied()
+                .collect(),
+        } * if power > 1 {
+            UntypedMonome {
+                powers: vec![(var.0, power - 1)],
+            }
+        } else {
+            UntypedMonome::default()
+        };
+        TypedPolynome {
+            monomes: vec![TypedMonome {
+                coeff: power as i64,
+                vars,
+            }],
+        }
+    }
+}
+
+impl From<Var> for UntypedMonome {
+    fn from(var: Var) -> Self {
+        Self {
+            powers: vec![(var.0, 1)],
+        }
+    }
+}
+
+impl<T: Into<UntypedMonome>> Mul<T> for UntypedMonome {
+    type Output = UntypedMonome;
+
+    /// Merges the two sorted power lists, summing powers of shared variables.
+    fn mul(self, rhs: T) -> UntypedMonome {
+        let rhs: UntypedMonome = rhs.into();
+        let mut powers = Vec::with_capacity(self.powers.len() + rhs.powers.len());
+        let mut left = self.powers.into_iter().peekable();
+        let mut right = rhs.powers.into_iter().peekable();
+        while let (Some(&l), Some(&r)) = (left.peek(), right.peek()) {
+            match l.0.cmp(&r.0) {
+                Ordering::Less => {
+                    powers.push(l);
+                    left.next();
+                }
+                Ordering::Greater => {
+                    powers.push(r);
+                    right.next();
+                }
+                Ordering::Equal => {
+                    powers.push((l.0, l.1 + r.1));
+                    left.next();
+                    right.next();
+                }
+            }
+        }
+        powers.extend(left);
+        powers.extend(right);
+        UntypedMonome { powers }
+    }
+}
+
+impl<T: Into<UntypedMonome>> Mul<T> for Var {
+    type Output = UntypedMonome;
+
+    fn mul(self, rhs: T) -> UntypedMonome {
+        let lhs: UntypedMonome = self.into();
+        lhs * rhs
+    }
+}
+
+impl Pow<usize> for UntypedMonome {
+    type Output = UntypedMonome;
+
+    /// Multiplies every power by `pow`; raising to the zeroth power yields
+    /// the unit monome.
+    fn pow(self, pow: usize) -> UntypedMonome {
+        if pow == 0 {
+            return UntypedMonome::default();
+        }
+        UntypedMonome {
+            powers: self
+                .powers
+                .into_iter()
+                .map(|(index, power)| (index, power * pow))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for UntypedMonome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.powers.is_empty() {
+            return write!(f, "1");
+        }
+        for (position, &(index, power)) in self.powers.iter().enumerate() {
+            if position > 0 {
+                write!(f, "*")?;
+            }
+            if power == 1 {
+                write!(f, "{}", Var(index))?;
+            } else {
+                write!(f, "{}^{}", Var(index), power)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num_traits::Pow;
+
+use crate::traits::CommutativeSemiring;
+use crate::typed_monome::TypedMonome;
+use crate::typed_polynome::TypedPolynome;
+use crate::untyped_monome::UntypedMonome;
+use crate::variables::Var;
+
+/// A formal sum of [`UntypedMonome`]s, without coefficients.
+///
+/// ```
+/// use rust_polynomes::variables::{X, Y};
+/// use rust_polynomes::UntypedPolynome;
+///
+/// let polynome: UntypedPolynome = X * X + X * Y;
+/// assert_eq!(polynome.monomes.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct UntypedPolynome {
+    pub monomes: Vec<UntypedMonome>,
+}
+
+impl UntypedPolynome {
+    /// Sorts the monomes into the canonical order. Duplicate monomes are
+    /// kept as is since there are no coefficients to merge them into.
+    pub fn order(&mut self) {
+        self.monomes.sort();
+    }
+
+    /// Consumes the polynome and returns it with the monomes sorted; the
+    /// expression-position counterpart of [`UntypedPolynome::order`].
+    pub fn ordered(mut self) -> Self {
+        self.order();
+        self
+    }
+
+    /// Returns the sorted form without consuming `self`.
+    pub fn to_ordered(&self) -> Self {
+        self.clone().ordered()
+    }
+
+    /// Returns each distinct monome paired with its multiplicity, in
+    /// canonical order. This is the bridge for promoting an untyped
+    /// polynome to a typed one with integer coefficients.
+    pub fn collect_terms(&self) -> Vec<(UntypedMonome, usize)> {
+        let mut monomes = self.monomes.clone();
+        monomes.sort();
+        let mut terms: Vec<(UntypedMonome, usize)> = Vec::new();
+        for monome in monomes {
+            match terms.last_mut() {
+                Some(last) if last.0 == monome => last.1 += 1,
+                _ => terms.push((monome, 1)),
+            }
+        }
+        terms
+    }
+
+    /// Groups identical monomes and returns them as a typed polynome whose
+    /// coefficients are the multiplicities, so the expansion of an untyped
+    /// product gets its multinomial coefficients.
+    ///
+    /// The blanket `From<UntypedPolynome>` conversion instead assigns
+    /// coefficient one to every listed monome; this is the counting
+    /// counterpart built on [`UntypedPolynome::collect_terms`].
+    pub fn count_terms(self) -> TypedPolynome<u64> {
+        TypedPolynome {
+            monomes: self
+                .collect_terms()
+                .into_iter()
+                .map(|(vars, count)| TypedMonome {
+                    coeff: count as u64,
+                    vars,
+                })
+                .collect(),
+        }
+    }
+
+    /// Promotes the polynome to a typed one by assigning the unit
+    /// coefficient to every monome and normalizing, so duplicate monomes
+    /// merge into integer multiplicities.
+    ///
+    /// This is the intended path to signed arithmetic: untyped polynomes
+    /// carry no coefficients and hence no `Sub` or `Neg`, so convert first
+    /// and subtract at the typed layer.
+    pub fn into_typed<T: CommutativeSemiring>(self) -> TypedPolynome<T> {
+        let mut answer: TypedPolynome<T> = self.into();
+        answer.order();
+        answer
+    }
+
+    /// Returns the sorted, deduplicated set of variables appearing in any
+    /// monome; empty for the empty polynome and for constants.
+    pub fn variables(&self) -> Vec<Var> {
+        let mut indices: Vec<usize> = self
+            .monomes
+            .iter()
+            .flat_map(|monome| monome.powers.iter().map(|&(index, _)| index))
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices.into_iter().map(Var).collect()
+    }
+
+    /// Returns the largest variable index appearing in any monome, `None`
+    /// for the empty polynome and for constants; the untyped counterpart
+    /// of [`TypedPolynome::max_variable`].
+    ///
+    /// [`TypedPolynome::max_variable`]: crate::TypedPolynome::max_variable
+    pub fn max_variable(&self) -> Option<Var> {
+        self.monomes
+            .iter()
+            .flat_map(|monome| monome.powers.iter().map(|&(index, _)| index))
+            .max()
+            .map(Var)
+    }
+}
+
+impl From<Var> for UntypedPolynome {
+    fn from(var: Var) -> Self {
+        Self {
+            monomes: vec![var.into()],
+        }
+    }
+}
+
+impl From<UntypedMonome> for UntypedPolynome {
+    fn from(monome: UntypedMonome) -> Self {
+        Self {
+            monomes: vec![monome],
+        }
+    }
+}
+
+impl<T: Into<UntypedPolynome>> Add<T> for UntypedPolynome {
+    type Output = UntypedPolynome;
+
+    fn add(mut self, rhs: T) -> UntypedPolynome {
+        self.monomes.extend(rhs.into().monomes);
+        self
+    }
+}
+
+impl<T: Into<UntypedPolynome>> Add<T> for UntypedMonome {
+    type Output = UntypedPolynome;
+
+    fn add(self, rhs: T) -> UntypedPolynome {
+        UntypedPolynome::from(self) + rhs.into()
+    }
+}
+
+impl<T: Into<UntypedPolynome>> Add<T> for Var {
+    type Output = UntypedPolynome;
+
+    fn add(self, rhs: T) -> UntypedPolynome {
+        UntypedPolynome::from(self) + rhs.into()
+    }
+}
+
+impl Neg for UntypedPolynome {
+    type Output = TypedPolynome<i64>;
+
+    /// Negation promotes to `i64` coefficients, since the untyped layer
+    /// has none of its own: every monome comes back with coefficient `-1`
+    /// (duplicates merged into multiplicities).
+    fn neg(self) -> TypedPolynome<i64> {
+        -self.into_typed::<i64>()
+    }
+}
+
+impl<T: Into<UntypedPolynome>> Sub<T> for UntypedPolynome {
+    type Output = TypedPolynome<i64>;
+
+    /// Subtraction promotes both sides through
+    /// [`UntypedPolynome::into_typed`] over `i64`, so `X - Y` "just works"
+    /// the way untyped `+` already does.
+    fn sub(self, rhs: T) -> TypedPolynome<i64> {
+        self.into_typed::<i64>() - rhs.into().into_typed::<i64>()
+    }
+}
+
+impl<T: Into<UntypedPolynome>> Sub<T> for UntypedMonome {
+    type Output = TypedPolynome<i64>;
+
+    fn sub(self, rhs: T) -> TypedPolynome<i64> {
+        UntypedPolynome::from(self) - rhs
+    }
+}
+
+impl<T: Into<UntypedPolynome>> Sub<T> for Var {
+    type Output = TypedPolynome<i64>;
+
+    fn sub(self, rhs: T) -> TypedPolynome<i64> {
+        UntypedPolynome::from(self) - rhs
+    }
+}
+
+impl<T: Into<UntypedPolynome>> Mul<T> for UntypedPolynome {
+    type Output = UntypedPolynome;
+
+    /// Returns the full cartesian product of the two monome lists, with the
+    /// outer loop over `self.monomes` and the inner loop over
+    /// `rhs.monomes`; the exact output order is part of the contract.
+    fn mul(self, rhs: T) -> UntypedPolynome {
+        let rhs: UntypedPolynome = rhs.into();
+        UntypedPolynome {
+            monomes: self
+                .monomes
+                .into_iter()
+                .flat_map(|monome| {
+                    rhs.monomes
+                        .iter()
+                        .map(move |other| monome.clone() * other.clone())
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Pow<usize> for UntypedPolynome {
+    type Output = UntypedPolynome;
+
+    /// Raising to the zeroth power yields the constant polynome `1`, the
+    /// single unit monome.
+    fn pow(self, pow: usize) -> UntypedPolynome {
+        if pow == 0 {
+            return UntypedPolynome {
+                monomes: vec![UntypedMonome::default()],
+            };
+        }
+        let mut answer = self.clone();
+        for _ in 1..pow {
+            answer = answer * self.clone();
+        }
+        answer
+    }
+}
+
+impl fmt::Display for UntypedPolynome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.monomes.is_empty() {
+            return write!(f, "0");
+        }
+        for (position, monome) in self.monomes.iter().enumerate() {
+            if position > 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{}", monome)?;
+        }
+        Ok(())
+    }
+}
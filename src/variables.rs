@@ -0,0 +1,137 @@
+use std::fmt;
+use std::fmt::Write;
+
+use crate::traits::CommutativeSemiring;
+use crate::TypedPolynome;
+
+/// A polynome variable, identified by its index.
+///
+/// Variables carry no name of their own; `Var(0)` prints as `x_0`. The
+/// constants [`X`], [`Y`] and [`Z`] cover the common small cases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Var(pub usize);
+
+/// The variable `x_0`.
+pub const X: Var = Var(0);
+/// The variable `x_1`.
+pub const Y: Var = Var(1);
+/// The variable `x_2`.
+pub const Z: Var = Var(2);
+
+impl fmt::Display for Var {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "x_{}", self.0)
+    }
+}
+
+/// Interns variable names to indices so polynomes can be displayed with
+/// readable names instead of the default `x_{i}`.
+#[derive(Debug, Clone, Default)]
+pub struct NamedContext {
+    names: Vec<String>,
+}
+
+impl NamedContext {
+    /// Creates an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the variable interned for `name`, allocating the next free
+    /// index on first use. Repeated names yield the same [`Var`].
+    pub fn var(&mut self, name: &str) -> Var {
+        match self.names.iter().position(|known| known == name) {
+            Some(index) => Var(index),
+            None => {
+                self.names.push(name.to_string());
+                Var(self.names.len() - 1)
+            }
+        }
+    }
+
+    /// Formats a polynome using the interned names; variables with indices
+    /// this context has never seen fall back to `x_{i}`.
+    pub fn format_polynome<T: CommutativeSemiring + fmt::Display>(
+        &self,
+        polynome: &TypedPolynome<T>,
+    ) -> String {
+        if polynome.monomes.is_empty() {
+            return "0".to_string();
+        }
+        let mut output = String::new();
+        for (position, monome) in polynome.monomes.iter().enumerate() {
+            if position > 0 {
+                output.push_str(" + ");
+            }
+            let _ = write!(output, "{}", monome.coeff);
+            for &(index, power) in &monome.vars.powers {
+                output.push('*');
+                match self.names.get(index) {
+                    Some(name) => output.push_str(name),
+                    None => {
+                        let _ = write!(output, "{}", Var(index));
+                    }
+                }
+                if power > 1 {
+                    let _ = write!(output, "^{}", power);
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Assigns contiguous variable indices and records their names, so values
+/// for a whole variable set can live in a plain slice indexed by position.
+///
+/// Unlike [`NamedContext`], which only affects display, a pool fixes the
+/// evaluation layout: [`TypedPolynome::substitute_dense`] reads the value
+/// of `Var(i)` straight from `values[i]` with no per-call map building.
+///
+/// [`TypedPolynome::substitute_dense`]: crate::TypedPolynome::substitute_dense
+#[derive(Debug, Clone, Default)]
+pub struct VarPool {
+    names: Vec<String>,
+}
+
+impl VarPool {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocates the next free index under its default `x_{i}` name.
+    pub fn fresh(&mut self) -> Var {
+        let var = Var(self.names.len());
+        self.names.push(var.to_string());
+        var
+    }
+
+    /// Returns the variable interned for `name`, allocating the next free
+    /// index on first use. Repeated names yield the same [`Var`].
+    pub fn named(&mut self, name: &str) -> Var {
+        match self.names.iter().position(|known| known == name) {
+            Some(index) => Var(index),
+            None => {
+                self.names.push(name.to_string());
+                Var(self.names.len() - 1)
+            }
+        }
+    }
+
+    /// Looks up the variable previously allocated for `name`.
+    pub fn index_of(&self, name: &str) -> Option<Var> {
+        self.names.iter().position(|known| known == name).map(Var)
+    }
+
+    /// Returns the number of variables allocated so far, the length a
+    /// dense value slice must have.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns whether the pool has allocated no variables yet.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
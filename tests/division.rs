@@ -0,0 +1,153 @@
+use num_rational::Ratio;
+use rust_polynomes::errors::DivisionError;
+use rust_polynomes::variables::{X, Y};
+use rust_polynomes::{Coeff, TypedPolynome};
+
+#[test]
+fn div_rem_exact() {
+    let dividend: TypedPolynome<f64> = Coeff(1.0) * X * X + Coeff(-1.0);
+    let divisor: TypedPolynome<f64> = Coeff(1.0) * X + Coeff(-1.0);
+    let (quotient, remainder) = dividend.div_rem(&divisor, X).unwrap();
+    let mut expected = Coeff(1.0) * X + Coeff(1.0);
+    expected.order();
+    assert_eq!(quotient, expected);
+    assert_eq!(remainder, TypedPolynome::zero());
+}
+
+#[test]
+fn div_rem_with_remainder() {
+    let dividend: TypedPolynome<f64> = Coeff(1.0) * X * X + Coeff(1.0);
+    let divisor: TypedPolynome<f64> = (Coeff(1.0) * X).into();
+    let (quotient, remainder) = dividend.div_rem(&divisor, X).unwrap();
+    assert_eq!(quotient, TypedPolynome::from(Coeff(1.0) * X));
+    assert_eq!(remainder, TypedPolynome::from(Coeff(1.0)));
+}
+
+#[test]
+fn div_rem_rejects_zero_divisor() {
+    let dividend: TypedPolynome<f64> = (Coeff(1.0) * X).into();
+    assert_eq!(
+        dividend.div_rem(&TypedPolynome::zero(), X),
+        Err(DivisionError::ZeroDivisor)
+    );
+}
+
+#[test]
+fn div_rem_rejects_multivariate() {
+    let dividend: TypedPolynome<f64> = (Coeff(1.0) * X * Y).into();
+    let divisor: TypedPolynome<f64> = (Coeff(1.0) * X).into();
+    assert_eq!(
+        dividend.div_rem(&divisor, X),
+        Err(DivisionError::NotUnivariate(1))
+    );
+}
+
+#[test]
+fn gcd_shared_factor() {
+    let first: TypedPolynome<f64> = Coeff(1.0) * X * X + Coeff(-1.0);
+    let second: TypedPolynome<f64> = Coeff(1.0) * X * X + Coeff(-2.0) * X + Coeff(1.0);
+    let gcd = first.gcd(&second, X).unwrap();
+    let mut expected = Coeff(1.0) * X + Coeff(-1.0);
+    expected.order();
+    assert_eq!(gcd, expected);
+}
+
+#[test]
+fn gcd_coprime_is_one() {
+    let first: TypedPolynome<f64> = Coeff(1.0) * X + Coeff(1.0);
+    let second: TypedPolynome<f64> = Coeff(1.0) * X + Coeff(2.0);
+    assert_eq!(first.gcd(&second, X).unwrap(), TypedPolynome::one());
+}
+
+#[test]
+fn gcd_with_zero_is_monic_operand() {
+    let first: TypedPolynome<f64> = (Coeff(3.0) * X).into();
+    let gcd = first.gcd(&TypedPolynome::zero(), X).unwrap();
+    assert_eq!(gcd, TypedPolynome::from(Coeff(1.0) * X));
+}
+
+#[test]
+fn div_rem_exact_over_rationals() {
+    let one = Ratio::from_integer(1i64);
+    let dividend: TypedPolynome<Ratio<i64>> = Coeff(one) * X * X + Coeff(-one);
+    let divisor: TypedPolynome<Ratio<i64>> = Coeff(one) * X + Coeff(-one);
+    let (quotient, remainder) = dividend.div_rem(&divisor, X).unwrap();
+    let mut expected = Coeff(one) * X + Coeff(one);
+    expected.order();
+    assert_eq!(quotient, expected);
+    assert_eq!(remainder, TypedPolynome::zero());
+}
+
+#[test]
+fn square_free_decomposition() {
+    // (x - 1)^2 * (x - 2)
+    let simple: TypedPolynome<f64> = Coeff(1.0) * X + Coeff(-1.0);
+    let double: TypedPolynome<f64> = Coeff(1.0) * X + Coeff(-2.0);
+    let polynome = simple.clone() * simple.clone() * double.clone();
+    let mut expected_double = simple;
+    expected_double.order();
+    let mut expected_simple = double;
+    expected_simple.order();
+    assert_eq!(
+        polynome.square_free(X).unwrap(),
+        vec![(expected_simple, 1), (expected_double, 2)]
+    );
+}
+
+#[test]
+fn square_free_of_square_free_input() {
+    let polynome: TypedPolynome<f64> = Coeff(1.0) * X * X + Coeff(-2.0);
+    let factors = polynome.square_free(X).unwrap();
+    assert_eq!(factors.len(), 1);
+    assert_eq!(factors[0].1, 1);
+    assert!(factors[0].0.equivalent(&polynome));
+    assert!(TypedPolynome::<f64>::one().square_free(X).unwrap().is_empty());
+}
+
+#[test]
+fn pow_mod_stays_reduced() {
+    // x^5 mod (x^2 - 2) = 4x, since x^2 = 2 in the quotient.
+    let base: TypedPolynome<f64> = (Coeff(1.0) * X).into();
+    let modulus: TypedPolynome<f64> = Coeff(1.0) * X * X + Coeff(-2.0);
+    let answer = base.pow_mod(5, &modulus, X).unwrap();
+    assert_eq!(answer, TypedPolynome::from(Coeff(4.0) * X));
+    assert!(answer.degree() < modulus.degree());
+
+    assert_eq!(base.pow_mod(0, &modulus, X).unwrap(), TypedPolynome::one());
+    assert_eq!(
+        base.pow_mod(3, &TypedPolynome::zero(), X),
+        Err(DivisionError::ZeroDivisor)
+    );
+}
+
+#[test]
+fn mul_karatsuba_matches_schoolbook() {
+    // Degree 80 operands exercise the recursive path past the crossover.
+    let left = TypedPolynome::from_coefficients(
+        X,
+        (0..=80).map(|power| (power % 7) as f64 - 3.0).collect(),
+    );
+    let right = TypedPolynome::from_coefficients(
+        X,
+        (0..=75).map(|power| (power % 5) as f64 + 1.0).collect(),
+    );
+    assert_eq!(
+        left.mul_karatsuba(&right, X).unwrap(),
+        (left.clone() * right.clone()).ordered()
+    );
+
+    let bivariate: TypedPolynome<f64> = (Coeff(1.0) * X * Y).into();
+    assert!(bivariate.mul_karatsuba(&right, X).is_err());
+}
+
+#[test]
+fn make_monic_divides_by_leading_coefficient() {
+    let polynome: TypedPolynome<f64> = Coeff(3.0) * X * X + Coeff(6.0) * X;
+    let monic = polynome.make_monic(X).unwrap();
+    let expected = (Coeff(1.0) * X * X + Coeff(2.0) * X).ordered();
+    assert_eq!(monic, expected);
+
+    assert_eq!(TypedPolynome::<f64>::zero().make_monic(X), None);
+    let bivariate: TypedPolynome<f64> = (Coeff(2.0) * X * Y).into();
+    assert_eq!(bivariate.make_monic(X), None);
+}
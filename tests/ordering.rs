@@ -0,0 +1,79 @@
+use rust_polynomes::ordering::MonomialOrder;
+use rust_polynomes::variables::{X, Y, Z};
+use rust_polynomes::{Coeff, TypedPolynome, UntypedMonome};
+
+#[test]
+fn order_by_lex_matches_order() {
+    let mut by_default: TypedPolynome<i32> = Coeff(1i32) * Y + Coeff(1i32) * X * X;
+    let mut by_lex = by_default.clone();
+    by_default.order();
+    by_lex.order_by(MonomialOrder::Lex);
+    assert_eq!(by_default, by_lex);
+}
+
+#[test]
+fn order_by_deglex_sorts_by_degree_first() {
+    let mut polynome: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(1i32) * Y + Coeff(1i32);
+    polynome.order_by(MonomialOrder::DegLex);
+    let degrees: Vec<usize> = polynome.monomes.iter().map(|m| m.degree()).collect();
+    assert_eq!(degrees, vec![0, 1, 2]);
+}
+
+#[test]
+fn deg_rev_lex_tie_breaking() {
+    // Among degree-2 monomes in x, y, z: x^2 > x*y > y^2 > x*z > y*z > z^2.
+    let x2: UntypedMonome = X * X;
+    let xy: UntypedMonome = X * Y;
+    let y2: UntypedMonome = Y * Y;
+    let xz: UntypedMonome = X * Z;
+    let zy: UntypedMonome = Z * Y;
+    let z2: UntypedMonome = Z * Z;
+    let ord = MonomialOrder::DegRevLex;
+    let mut monomes = vec![z2.clone(), y2.clone(), xz.clone(), x2.clone(), zy.clone(), xy.clone()];
+    monomes.sort_by(|a, b| ord.compare(b, a));
+    assert_eq!(monomes, vec![x2, xy, y2, xz, zy, z2]);
+}
+
+#[test]
+fn order_by_merges_like_terms() {
+    let mut polynome: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(2i32) * X;
+    polynome.order_by(MonomialOrder::DegRevLex);
+    assert_eq!(polynome.monomes.len(), 1);
+    assert_eq!(polynome.monomes[0].coeff, 3);
+}
+
+#[test]
+fn leading_term_and_coefficient() {
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X * X + Coeff(5i32) * Y + Coeff(1i32);
+    let leading = polynome.leading_term(MonomialOrder::DegLex).unwrap();
+    assert_eq!(leading.coeff, 2);
+    assert_eq!(leading.vars, X * X);
+    assert_eq!(polynome.leading_coefficient(MonomialOrder::DegLex), Some(2));
+    assert_eq!(
+        TypedPolynome::<i32>::zero().leading_coefficient(MonomialOrder::Lex),
+        None
+    );
+}
+
+#[test]
+fn leading_term_merges_duplicates_first() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(1i32) * X;
+    let leading = polynome.leading_term(MonomialOrder::Lex).unwrap();
+    assert_eq!(leading.coeff, 2);
+}
+
+#[test]
+fn monomial_cmp_matches_canonical_sort() {
+    use rust_polynomes::ordering::monomial_cmp;
+    use std::cmp::Ordering;
+
+    let xy: UntypedMonome = X * Y;
+    let x2: UntypedMonome = X * X;
+    assert_eq!(monomial_cmp(&xy, &x2), Ordering::Less);
+    assert_eq!(monomial_cmp(&xy, &xy), Ordering::Equal);
+
+    let mut polynome: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(1i32) * X * Y;
+    polynome.order();
+    let sorted: Vec<&UntypedMonome> = polynome.monomes.iter().map(|m| &m.vars).collect();
+    assert!(sorted.windows(2).all(|pair| monomial_cmp(pair[0], pair[1]) == Ordering::Less));
+}
@@ -0,0 +1,79 @@
+use rust_polynomes::errors::ParseError;
+use rust_polynomes::variables::{X, Y};
+use rust_polynomes::{Coeff, TypedPolynome};
+
+#[test]
+fn parse_univariate() {
+    let polynome: TypedPolynome<i32> = "2*x^2 + 3*x - 1".parse().unwrap();
+    let mut expected = Coeff(2i32) * X * X + Coeff(3i32) * X + Coeff(-1i32);
+    expected.order();
+    assert_eq!(polynome, expected);
+}
+
+#[test]
+fn parse_implicit_coefficients() {
+    let polynome: TypedPolynome<i32> = "x^2 + x*y - y".parse().unwrap();
+    let mut expected = Coeff(1i32) * X * X + Coeff(1i32) * X * Y + Coeff(-1i32) * Y;
+    expected.order();
+    assert_eq!(polynome, expected);
+}
+
+#[test]
+fn parse_constant() {
+    let polynome: TypedPolynome<u32> = "7".parse().unwrap();
+    assert_eq!(polynome, TypedPolynome::from(Coeff(7u32)));
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        "".parse::<TypedPolynome<i32>>(),
+        Err(ParseError::UnexpectedEnd)
+    );
+    assert_eq!(
+        "2*x +".parse::<TypedPolynome<i32>>(),
+        Err(ParseError::UnexpectedEnd)
+    );
+    assert_eq!(
+        "2*q".parse::<TypedPolynome<i32>>(),
+        Err(ParseError::UnknownToken("q".to_string()))
+    );
+    assert!(matches!(
+        "-x".parse::<TypedPolynome<u32>>(),
+        Err(ParseError::InvalidCoefficient(_))
+    ));
+}
+
+#[test]
+fn parse_sign_applies_to_whole_term() {
+    let polynome: TypedPolynome<i32> = "-x*2 + 3".parse().unwrap();
+    let mut expected = Coeff(-2i32) * X + Coeff(3i32);
+    expected.order();
+    assert_eq!(polynome, expected);
+}
+
+#[test]
+fn parse_display_round_trip() {
+    let mut polynome: TypedPolynome<i32> = Coeff(2i32) * X * X + Coeff(3i32) * Y + Coeff(4i32);
+    polynome.order();
+    let text = format!("{}", polynome);
+    let reparsed: TypedPolynome<i32> = text
+        .replace("x_0", "x")
+        .replace("x_1", "y")
+        .parse()
+        .unwrap();
+    assert_eq!(reparsed, polynome);
+}
+
+#[test]
+fn parse_display_round_trip_negative() {
+    let mut polynome: TypedPolynome<i32> = Coeff(2i32) * X * X + Coeff(-3i32) * Y + Coeff(-4i32);
+    polynome.order();
+    let text = format!("{}", polynome);
+    let reparsed: TypedPolynome<i32> = text
+        .replace("x_0", "x")
+        .replace("x_1", "y")
+        .parse()
+        .unwrap();
+    assert_eq!(reparsed, polynome);
+}
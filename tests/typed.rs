@@ -0,0 +1,1388 @@
+use std::collections::HashMap;
+
+use num_bigint::BigInt;
+use num_rational::Ratio;
+use num_traits::Pow;
+use rust_polynomes::errors::{DivisionError, ExpansionError, SubstitutionError};
+use rust_polynomes::variables::{Var, VarPool, X, Y, Z};
+use rust_polynomes::{interpolate, jacobian, Coeff, TypedMonome, TypedPolynome, TypedPolynomeBuilder, UntypedMonome};
+
+#[test]
+fn monome_construction() {
+    let monome: TypedMonome<u32> = Coeff(3u32) * X * Y;
+    assert_eq!(monome.coeff, 3u32);
+    assert_eq!(monome.vars.powers, vec![(0, 1), (1, 1)]);
+}
+
+#[test]
+fn monome_neg() {
+    let monome: TypedMonome<i32> = -(Coeff(3i32) * X);
+    assert_eq!(monome.coeff, -3i32);
+}
+
+#[test]
+fn polynome_order_merges_like_terms() {
+    let polynome = TypedPolynome::<i32>::from(X + Y) * (TypedPolynome::<i32>::from(X) - Y);
+    assert_eq!(polynome.monomes.len(), 2);
+    let mut expected: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(-1i32) * Y * Y;
+    expected.order();
+    assert_eq!(polynome, expected);
+}
+
+#[test]
+fn polynome_assign_operators() {
+    let mut accumulator: TypedPolynome<i32> = TypedPolynome::zero();
+    accumulator += Coeff(2i32) * X;
+    accumulator += Y;
+    accumulator -= Coeff(1i32) * X;
+    accumulator *= TypedPolynome::<i32>::from(X);
+    accumulator.order();
+    let mut expected = Coeff(1i32) * X * X + Coeff(1i32) * X * Y;
+    expected.order();
+    assert_eq!(accumulator, expected);
+}
+
+#[test]
+fn polynome_sum_and_product() {
+    let terms = vec![Coeff(1u32) * X, Coeff(2u32) * Y, Coeff(3u32) * X];
+    let mut sum: TypedPolynome<u32> = terms.into_iter().sum();
+    sum.order();
+    let mut expected = Coeff(4u32) * X + Coeff(2u32) * Y;
+    expected.order();
+    assert_eq!(sum, expected);
+
+    let factors: Vec<TypedPolynome<u32>> = vec![(X + Y).into(), (X + Y).into()];
+    let product: TypedPolynome<u32> = factors.into_iter().product();
+    assert_eq!(product, TypedPolynome::<u32>::from(X + Y).pow(2));
+
+    let empty: TypedPolynome<u32> = std::iter::empty::<TypedPolynome<u32>>().sum();
+    assert_eq!(empty, TypedPolynome::zero());
+}
+
+#[test]
+fn polynome_variables() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * Z * X + Coeff(2i32) * Y + Coeff(7i32);
+    assert_eq!(polynome.variables(), vec![X, Y, Z]);
+    assert_eq!(TypedPolynome::<i32>::zero().variables(), vec![]);
+    assert_eq!(TypedPolynome::<i32>::one().variables(), vec![]);
+}
+
+#[test]
+fn polynome_contains_variable() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X * Y + Coeff(2i32);
+    assert!(polynome.contains_variable(X));
+    assert!(polynome.contains_variable(Y));
+    assert!(!polynome.contains_variable(Z));
+    assert!(!TypedPolynome::<i32>::zero().contains_variable(X));
+}
+
+#[test]
+fn polynome_remap_variables() {
+    let mut polynome: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(2i32) * Y;
+    polynome.remap_variables(|var| Var(var.0 + 3));
+    assert_eq!(polynome.variables(), vec![Var(3), Var(4)]);
+
+    let mut collided: TypedPolynome<i32> = (X * Y).into();
+    collided.remap_variables(|_| Var(5));
+    assert_eq!(collided.monomes[0].vars.powers, vec![(5, 2)]);
+
+    let mut renamed: TypedPolynome<i32> = (X * Y).into();
+    renamed.rename_variable(Y, Z);
+    assert_eq!(renamed.monomes[0].vars.powers, vec![(0, 1), (2, 1)]);
+}
+
+#[test]
+fn polynome_coefficient_of() {
+    let polynome: TypedPolynome<i32> =
+        Coeff(2i32) * X * Y + Coeff(3i32) * X * Y + Coeff(4i32) * X;
+    assert_eq!(polynome.coefficient_of(&(Y * X)), 5);
+    assert_eq!(polynome.coefficient_of(&X.into()), 4);
+    assert_eq!(polynome.coefficient_of(&(X * Z)), 0);
+}
+
+#[test]
+fn polynome_equivalent() {
+    let lhs: TypedPolynome<i32> = (X + Y).into();
+    let rhs: TypedPolynome<i32> = (Y + X).into();
+    assert_ne!(lhs, rhs);
+    assert!(lhs.equivalent(&rhs));
+    let duplicated: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(1i32) * X;
+    let merged: TypedPolynome<i32> = (Coeff(2i32) * X).into();
+    assert!(duplicated.equivalent(&merged));
+    assert!(!lhs.equivalent(&merged));
+}
+
+#[test]
+fn polynome_iteration() {
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X + Coeff(0i32) * Y + Coeff(3i32);
+    let borrowed_degrees: Vec<usize> = (&polynome).into_iter().map(|m| m.degree()).collect();
+    assert_eq!(borrowed_degrees, vec![1, 1, 0]);
+    let filtered: TypedPolynome<i32> = polynome.into_iter().filter(|m| m.coeff != 0).collect();
+    assert_eq!(filtered.monomes.len(), 2);
+}
+
+#[test]
+fn polynome_map_coefficients() {
+    let polynome: TypedPolynome<i64> = Coeff(3i64) * X + Coeff(4i64) * Y;
+    let floats = polynome.map_coefficients(|&coeff| coeff as f64);
+    assert_eq!(floats, Coeff(3.0) * X + Coeff(4.0) * Y);
+    let modulo = polynome.map_coefficients_reduced(|&coeff| coeff % 2);
+    assert_eq!(modulo, TypedPolynome::from(Coeff(1i64) * X));
+}
+
+#[test]
+fn polynome_classification_helpers() {
+    let constant: TypedPolynome<i32> = Coeff(3i32) + Coeff(4i32);
+    assert!(constant.is_constant());
+    assert_eq!(constant.constant_term(), 7);
+    assert!(constant.is_homogeneous());
+
+    let mixed: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(2i32) * Y + Coeff(5i32);
+    assert!(!mixed.is_constant());
+    assert_eq!(mixed.constant_term(), 5);
+    assert!(!mixed.is_homogeneous());
+
+    let quadratic: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(2i32) * X * Y;
+    assert!(quadratic.is_homogeneous());
+    assert_eq!(quadratic.constant_term(), 0);
+    assert!(TypedPolynome::<i32>::zero().is_constant());
+    assert!(TypedPolynome::<i32>::zero().is_homogeneous());
+}
+
+#[test]
+fn polynome_substitute() {
+    let polynome: TypedPolynome<u32> = Coeff(2u32) * X * X + Y + Coeff(5u32);
+    assert_eq!(polynome.substitute(vec![(X, 3u32), (Y, 1u32)]), Ok(24u32));
+}
+
+#[test]
+fn polynome_substitute_missing_variable() {
+    let polynome: TypedPolynome<u32> = (X * Y).into();
+    assert_eq!(
+        polynome.substitute(vec![(X, 1u32)]),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn polynome_substitute_repeating_variable() {
+    let polynome: TypedPolynome<u32> = X.into();
+    assert_eq!(
+        polynome.substitute(vec![(X, 1u32), (X, 2u32)]),
+        Err(SubstitutionError::RepeatingVariable(0))
+    );
+}
+
+#[test]
+fn polynome_coefficient_vector_round_trip() {
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X * X * X + Coeff(1i32) * X + Coeff(5i32);
+    assert_eq!(polynome.to_coefficients(X), Ok(vec![5, 1, 0, 2]));
+    let rebuilt = TypedPolynome::from_coefficients(X, vec![5, 1, 0, 2]);
+    assert!(rebuilt.equivalent(&polynome));
+    let bivariate: TypedPolynome<i32> = (X * Y).into();
+    assert_eq!(
+        bivariate.to_coefficients(X),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn polynome_eval_horner() {
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X * X * X + Coeff(1i32) * X + Coeff(5i32);
+    assert_eq!(polynome.eval_horner(X, 3), Ok(62));
+    let bivariate: TypedPolynome<i32> = (X * Y).into();
+    assert_eq!(
+        bivariate.eval_horner(X, 1),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn polynome_substitute_partial() {
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X * X * Y + Coeff(3i32) * Z + Coeff(1i32);
+    let answer = polynome.substitute_partial(vec![(X, 2)]).unwrap();
+    let mut expected = Coeff(8i32) * Y + Coeff(3i32) * Z + Coeff(1i32);
+    expected.order();
+    assert_eq!(answer, expected);
+    assert_eq!(
+        polynome.substitute_partial(vec![(X, 1), (X, 2)]),
+        Err(SubstitutionError::RepeatingVariable(0))
+    );
+}
+
+#[test]
+fn polynome_substitute_map() {
+    let polynome: TypedPolynome<u32> = Coeff(2u32) * X * X + Y;
+    let values: HashMap<usize, u32> = [(0, 3u32), (1, 1u32)].into_iter().collect();
+    assert_eq!(polynome.substitute_map(&values), Ok(19u32));
+    let missing: HashMap<usize, u32> = [(0, 3u32)].into_iter().collect();
+    assert_eq!(
+        polynome.substitute_map(&missing),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn polynome_substitute_many() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(1i32) * Y;
+    let points = vec![
+        vec![(X, 1), (Y, 1)],
+        vec![(X, 2), (Y, 3)],
+        vec![(X, 0), (Y, -1)],
+    ];
+    assert_eq!(polynome.substitute_many(&points), Ok(vec![2, 7, -1]));
+    assert_eq!(
+        polynome.substitute_many(&[vec![(X, 1)]]),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn polynome_substitute_polynome() {
+    let polynome: TypedPolynome<i32> = (X * X).into();
+    let sub: TypedPolynome<i32> = (X + Y).into();
+    let mut answer = polynome.substitute_polynome(X, sub);
+    answer.order();
+    let mut expected = Coeff(1i32) * X * X + Coeff(2i32) * X * Y + Coeff(1i32) * Y * Y;
+    expected.order();
+    assert_eq!(answer, expected);
+}
+
+#[test]
+fn polynome_compose() {
+    let mut polynome: TypedPolynome<i32> = (X * Y).into();
+    polynome
+        .compose(vec![(X, (Y + Z).into()), (Y, TypedPolynome::from(X))])
+        .unwrap();
+    let mut expected = Coeff(1i32) * X * Y + Coeff(1i32) * X * Z;
+    expected.order();
+    assert_eq!(polynome, expected);
+
+    let mut repeated: TypedPolynome<i32> = X.into();
+    assert_eq!(
+        repeated.compose(vec![(X, TypedPolynome::one()), (X, TypedPolynome::one())]),
+        Err(SubstitutionError::RepeatingVariable(0))
+    );
+}
+
+#[test]
+fn polynome_derivative() {
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X * X * X + Coeff(4i32) * X + Y;
+    let mut derivative = polynome.derivative(X);
+    derivative.order();
+    let mut expected = Coeff(6i32) * X * X + Coeff(4i32);
+    expected.order();
+    assert_eq!(derivative, expected);
+}
+
+#[test]
+fn system_jacobian() {
+    let system: Vec<TypedPolynome<i32>> = vec![
+        Coeff(1i32) * X * X + Coeff(1i32) * Y,
+        (Coeff(1i32) * X * Y).into(),
+    ];
+    let matrix = jacobian(&system, &[X, Y]);
+    assert_eq!(matrix.len(), 2);
+    assert!(matrix[0][0].equivalent(&(Coeff(2i32) * X).into()));
+    assert!(matrix[0][1].equivalent(&TypedPolynome::one()));
+    assert!(matrix[1][0].equivalent(&TypedPolynome::from(Coeff(1i32) * Y)));
+    assert!(matrix[1][1].equivalent(&TypedPolynome::from(Coeff(1i32) * X)));
+    assert!(jacobian::<i32>(&[], &[X]).is_empty());
+    assert_eq!(jacobian(&system, &[]), vec![vec![], Vec::<TypedPolynome<i32>>::new()]);
+}
+
+#[test]
+fn polynome_gradient() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(1i32) * X * Y;
+    let gradient = polynome.gradient(&[X, Y]);
+    let mut by_x = Coeff(2i32) * X + Coeff(1i32) * Y;
+    by_x.order();
+    assert_eq!(gradient.len(), 2);
+    assert!(gradient[0].equivalent(&by_x));
+    assert!(gradient[1].equivalent(&TypedPolynome::from(Coeff(1i32) * X)));
+}
+
+#[test]
+fn polynome_integrate() {
+    let polynome: TypedPolynome<f64> = (Coeff(3.0) * X * X).into();
+    assert_eq!(polynome.integrate(X), TypedPolynome::from(Coeff(1.0) * X * X * X));
+    let constant: TypedPolynome<f64> = Coeff(2.0).into();
+    assert_eq!(constant.integrate(X), TypedPolynome::from(Coeff(2.0) * X));
+}
+
+#[test]
+fn polynome_integrate_derivative_round_trip() {
+    let polynome: TypedPolynome<f64> = Coeff(4.0) * X * X + Coeff(2.0) * X * Y + Coeff(3.0);
+    assert!(polynome.integrate(X).derivative(X).equivalent(&polynome));
+}
+
+#[test]
+fn polynome_degree() {
+    let polynome: TypedPolynome<u32> = Coeff(1u32) * X * X * Y + Z;
+    assert_eq!(polynome.degree(), 3);
+    assert_eq!(TypedPolynome::<u32>::zero().degree(), 0);
+}
+
+#[test]
+fn polynome_degree_in() {
+    let polynome: TypedPolynome<u32> = Coeff(1u32) * X * X * Y + Coeff(3u32) * Y + Z;
+    assert_eq!(polynome.degree_in(X), 2);
+    assert_eq!(polynome.degree_in(Y), 1);
+    assert_eq!(polynome.degree_in(Z), 1);
+    assert_eq!(TypedPolynome::<u32>::zero().degree_in(X), 0);
+}
+
+#[test]
+fn polynome_pow() {
+    let mut polynome: TypedPolynome<u32> = TypedPolynome::from(X + Y).pow(2);
+    polynome.order();
+    let mut expected = Coeff(1u32) * X * X + Coeff(2u32) * X * Y + Coeff(1u32) * Y * Y;
+    expected.order();
+    assert_eq!(polynome, expected);
+    assert_eq!(
+        TypedPolynome::<u32>::from(X + Y).pow(0),
+        TypedPolynome::<u32>::one()
+    );
+}
+
+#[test]
+fn polynome_pow_collapses_like_terms() {
+    let polynome: TypedPolynome<u64> = TypedPolynome::from(X + Y).pow(10);
+    assert_eq!(polynome.monomes.len(), 11);
+    assert_eq!(
+        polynome.substitute(vec![(X, 1u64), (Y, 1u64)]),
+        Ok(1u64 << 10)
+    );
+}
+
+#[test]
+fn polynome_pow_bounded() {
+    let polynome: TypedPolynome<u64> = (X + Y).into();
+    let bounded = polynome.pow_bounded(10, 16).unwrap();
+    assert_eq!(bounded, polynome.clone().pow(10));
+    assert_eq!(
+        TypedPolynome::<u64>::from(X + Y + Z).pow_bounded(20, 16),
+        Err(ExpansionError::TooManyTerms)
+    );
+}
+
+#[test]
+#[ignore = "benchmark; run with --ignored to compare against the naive fold"]
+fn bench_pow_bounded_vs_naive_pow() {
+    let polynome: TypedPolynome<u64> = (X + Y).into();
+    let start = std::time::Instant::now();
+    let bounded = polynome.pow_bounded(32, 64).unwrap();
+    let fast = start.elapsed();
+    let start = std::time::Instant::now();
+    let mut naive = polynome.clone();
+    for _ in 1..32 {
+        naive *= polynome.clone();
+    }
+    naive.order();
+    let slow = start.elapsed();
+    assert_eq!(bounded, naive);
+    println!("pow_bounded: {:?}, naive fold: {:?}", fast, slow);
+}
+
+#[test]
+fn polynome_homogeneous_decomposition() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(2i32) * X * Y + X + Coeff(7i32);
+    let parts = polynome.homogeneous_decomposition();
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts[&0], TypedPolynome::from(Coeff(7i32)));
+    assert_eq!(parts[&1], TypedPolynome::<i32>::from(X));
+    let mut quadratic = Coeff(1i32) * X * X + Coeff(2i32) * X * Y;
+    quadratic.order();
+    assert_eq!(parts[&2], quadratic);
+}
+
+#[test]
+fn polynome_leading_form() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(1i32) * X * Y + Y + Coeff(1i32);
+    let mut expected = Coeff(1i32) * X * X + Coeff(1i32) * X * Y;
+    expected.order();
+    assert_eq!(polynome.leading_form(), expected);
+    assert_eq!(TypedPolynome::<i32>::zero().leading_form(), TypedPolynome::zero());
+}
+
+#[test]
+fn polynome_bigint_coefficients() {
+    let polynome: TypedPolynome<BigInt> =
+        Coeff(BigInt::from(2)) * X * X + Coeff(BigInt::from(3)) * Y;
+    let derivative = polynome.derivative(X);
+    assert_eq!(
+        derivative,
+        TypedPolynome::from(Coeff(BigInt::from(4)) * X)
+    );
+    assert_eq!(
+        polynome.substitute(vec![(X, BigInt::from(5)), (Y, BigInt::from(1))]),
+        Ok(BigInt::from(53))
+    );
+}
+
+#[test]
+fn polynome_div_scalar() {
+    let polynome: TypedPolynome<f64> = Coeff(3.0) * X + Coeff(6.0) * Y;
+    let halved = polynome.div_scalar(3.0).unwrap();
+    let mut expected = Coeff(1.0) * X + Coeff(2.0) * Y;
+    expected.order();
+    let mut halved = halved;
+    halved.order();
+    assert_eq!(halved, expected);
+    assert_eq!(polynome.div_scalar(0.0), Err(DivisionError::ZeroDivisor));
+}
+
+#[test]
+fn polynome_reduce_fractions() {
+    let polynome: TypedPolynome<Ratio<i64>> =
+        Coeff(Ratio::new_raw(4, 8)) * X + Coeff(Ratio::new_raw(6, 3)) * Y + Coeff(Ratio::new_raw(0, 5));
+    let reduced = polynome.reduce_fractions();
+    let mut expected = Coeff(Ratio::new(1, 2)) * X + Coeff(Ratio::new(2, 1)) * Y;
+    expected.order();
+    assert_eq!(reduced, expected);
+}
+
+#[test]
+fn polynome_display() {
+    let polynome: TypedPolynome<u32> = Coeff(2u32) * X * X + Coeff(3u32) * Y + Coeff(1u32);
+    assert_eq!(format!("{}", polynome), "2*x_0^2 + 3*x_1 + 1");
+}
+
+#[test]
+fn polynome_find_root_newton() {
+    let polynome: TypedPolynome<f64> = Coeff(1.0) * X * X + Coeff(-2.0);
+    let root = polynome.find_root_newton(X, 1.0, 100, 1e-12).unwrap();
+    assert!((root - 2.0f64.sqrt()).abs() < 1e-6);
+    // A constant polynome has zero derivative everywhere.
+    let constant: TypedPolynome<f64> = TypedPolynome::from(Coeff(1.0));
+    assert_eq!(constant.find_root_newton(X, 0.0, 100, 1e-12), None);
+    // Extra variables cannot be evaluated and are rejected as None.
+    let bivariate: TypedPolynome<f64> = (Coeff(1.0) * X * Y).into();
+    assert_eq!(bivariate.find_root_newton(X, 1.0, 100, 1e-12), None);
+}
+
+#[test]
+fn polynome_to_latex() {
+    let polynome: TypedPolynome<i32> =
+        Coeff(2i32) * X * X * Y + Coeff(1i32) * X + Coeff(-1i32) * Y + Coeff(3i32);
+    assert_eq!(polynome.to_latex(&["x", "y"]), "3 + x + 2x^{2}y - y");
+    assert_eq!(polynome.to_latex(&["x"]), "3 + x + 2x^{2}x_{1} - x_{1}");
+    assert_eq!(TypedPolynome::<i32>::zero().to_latex(&[]), "0");
+}
+
+#[test]
+fn named_context_formatting() {
+    use rust_polynomes::variables::NamedContext;
+
+    let mut context = NamedContext::new();
+    let alpha = context.var("alpha");
+    let beta = context.var("beta");
+    assert_eq!(context.var("alpha"), alpha);
+    assert_ne!(alpha, beta);
+
+    let mut polynome: TypedPolynome<i32> =
+        Coeff(2i32) * alpha * beta + Coeff(3i32) * alpha + Coeff(1i32) * Z;
+    polynome.order();
+    assert_eq!(
+        context.format_polynome(&polynome),
+        "3*alpha + 2*alpha*beta + 1*x_2"
+    );
+}
+
+#[test]
+fn polynome_substitute_ordered_matrices() {
+    use std::ops::{Add, Mul};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Mat2([[i32; 2]; 2]);
+
+    impl Add for Mat2 {
+        type Output = Mat2;
+
+        fn add(self, rhs: Mat2) -> Mat2 {
+            let mut answer = [[0; 2]; 2];
+            for (row, answer_row) in answer.iter_mut().enumerate() {
+                for (column, entry) in answer_row.iter_mut().enumerate() {
+                    *entry = self.0[row][column] + rhs.0[row][column];
+                }
+            }
+            Mat2(answer)
+        }
+    }
+
+    impl Mul for Mat2 {
+        type Output = Mat2;
+
+        fn mul(self, rhs: Mat2) -> Mat2 {
+            let mut answer = [[0; 2]; 2];
+            for (row, answer_row) in answer.iter_mut().enumerate() {
+                for (column, entry) in answer_row.iter_mut().enumerate() {
+                    for middle in 0..2 {
+                        *entry += self.0[row][middle] * rhs.0[middle][column];
+                    }
+                }
+            }
+            Mat2(answer)
+        }
+    }
+
+    impl num_traits::Zero for Mat2 {
+        fn zero() -> Mat2 {
+            Mat2([[0; 2]; 2])
+        }
+
+        fn is_zero(&self) -> bool {
+            *self == Mat2::zero()
+        }
+    }
+
+    impl num_traits::One for Mat2 {
+        fn one() -> Mat2 {
+            Mat2([[1, 0], [0, 1]])
+        }
+    }
+
+    impl From<i32> for Mat2 {
+        fn from(value: i32) -> Mat2 {
+            Mat2([[value, 0], [0, value]])
+        }
+    }
+
+    let polynome: TypedPolynome<i32> = (Coeff(1i32) * X * Y).into();
+    let a = Mat2([[1, 2], [3, 4]]);
+    let b = Mat2([[0, 1], [1, 0]]);
+    // The factors always multiply in variable-index order, regardless of
+    // the order the caller supplies the values.
+    assert_eq!(
+        polynome.substitute_ordered(vec![(Y, b), (X, a)]),
+        Ok(a * b)
+    );
+    assert_eq!(
+        polynome.substitute_ordered(vec![(X, a), (Y, b)]),
+        Ok(a * b)
+    );
+    assert_ne!(a * b, b * a);
+}
+
+#[test]
+fn polynome_len_counts_normalized_terms() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(1i32) * X + Coeff(0i32) * Y;
+    assert_eq!(polynome.monomes.len(), 3);
+    assert_eq!(polynome.len(), 1);
+    assert!(!polynome.is_empty());
+
+    let cancelling: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(-1i32) * X;
+    assert_eq!(cancelling.len(), 0);
+    assert!(cancelling.is_empty());
+    assert!(TypedPolynome::<i32>::zero().is_empty());
+}
+
+#[test]
+fn polynome_compose_univariate() {
+    let outer: TypedPolynome<i32> = (Coeff(1i32) * X * X).into();
+    let inner: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(1i32);
+    let mut expected = Coeff(1i32) * X * X + Coeff(2i32) * X + Coeff(1i32);
+    expected.order();
+    assert_eq!(outer.compose_univariate(X, &inner), expected);
+
+    // Other variables survive as coefficients.
+    let mixed: TypedPolynome<i32> = Coeff(1i32) * X * Y + Coeff(1i32) * Y;
+    let composed = mixed.compose_univariate(X, &inner);
+    let mut expected = Coeff(1i32) * X * Y + Coeff(2i32) * Y;
+    expected.order();
+    assert_eq!(composed, expected);
+
+    // Composition agrees with the naive substitution.
+    let deep: TypedPolynome<i32> = Coeff(1i32) * X * X * X + Coeff(-1i32) * X + Coeff(5i32);
+    let mut naive = deep.substitute_polynome(X, inner.clone());
+    naive.order();
+    assert_eq!(deep.compose_univariate(X, &inner), naive);
+}
+
+#[test]
+fn polynome_hash_uses_normalized_form() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of(polynome: &TypedPolynome<i32>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        polynome.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let unordered: TypedPolynome<i32> = Coeff(1i32) * Y + Coeff(1i32) * X + Coeff(0i32) * Z;
+    let ordered = unordered.normalized();
+    assert_ne!(unordered, ordered);
+    assert_eq!(hash_of(&unordered), hash_of(&ordered));
+
+    let mut memo = std::collections::HashMap::new();
+    memo.insert(ordered.clone(), 42);
+    assert_eq!(memo.get(&ordered), Some(&42));
+}
+
+#[test]
+fn polynome_content_and_primitive_part() {
+    let polynome: TypedPolynome<i64> = Coeff(-6i64) * X * X + Coeff(-9i64) * Y + Coeff(12i64);
+    assert_eq!(polynome.content(), 3);
+    let primitive = polynome.primitive_part();
+    let mut expected: TypedPolynome<i64> =
+        Coeff(2i64) * X * X + Coeff(3i64) * Y + Coeff(-4i64);
+    expected.order();
+    assert_eq!(primitive, expected);
+
+    let single: TypedPolynome<i64> = (Coeff(-5i64) * X).into();
+    assert_eq!(single.content(), 5);
+    assert_eq!(single.primitive_part(), TypedPolynome::from(Coeff(1i64) * X));
+    assert_eq!(TypedPolynome::<i64>::zero().content(), 0);
+    assert_eq!(
+        TypedPolynome::<i64>::zero().primitive_part(),
+        TypedPolynome::zero()
+    );
+}
+
+#[test]
+fn polynome_eval_gradient() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X * X * Y + Coeff(3i32) * Y;
+    let gradient = polynome
+        .eval_gradient(&[X, Y], &[(X, 2i32), (Y, 5i32)])
+        .unwrap();
+    assert_eq!(gradient, vec![20, 7]);
+    assert_eq!(
+        polynome.eval_gradient(&[X], &[(X, 2i32)]),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn polynome_builder() {
+    let mut builder = TypedPolynomeBuilder::new();
+    builder
+        .term(2i32, &[(X, 1), (Y, 1)])
+        .term(1i32, &[(Y, 1), (X, 1)])
+        .term(4i32, &[(X, 1), (X, 1)])
+        .term(7i32, &[(Z, 0)]);
+    let mut expected: TypedPolynome<i32> =
+        Coeff(3i32) * X * Y + Coeff(4i32) * X * X + Coeff(7i32);
+    expected.order();
+    assert_eq!(builder.build(), expected);
+    assert_eq!(
+        TypedPolynomeBuilder::<i32>::new().build(),
+        TypedPolynome::zero()
+    );
+}
+
+#[test]
+fn polynome_try_substitute_rejects_unknown_variables() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(1i32) * Y;
+    assert_eq!(
+        polynome.try_substitute(vec![(X, 1i32), (Y, 2i32)]),
+        Ok(3i32)
+    );
+    assert_eq!(
+        polynome.try_substitute(vec![(X, 1i32), (Y, 2i32), (Var(999), 0i32)]),
+        Err(SubstitutionError::UnknownVariable(Var(999)))
+    );
+    // The lenient variant still ignores the extra value.
+    assert_eq!(
+        polynome.substitute(vec![(X, 1i32), (Y, 2i32), (Var(999), 0i32)]),
+        Ok(3i32)
+    );
+}
+
+#[test]
+fn polynome_homogeneous_components() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(1i32) * X + Coeff(1i32);
+    let components = polynome.homogeneous_components();
+    assert_eq!(components.len(), 3);
+    assert_eq!(components[0], TypedPolynome::from(Coeff(1i32)));
+    assert_eq!(components[1], TypedPolynome::from(Coeff(1i32) * X));
+    assert_eq!(components[2], TypedPolynome::from(Coeff(1i32) * X * X));
+
+    // Gaps are padded with the zero polynome.
+    let sparse: TypedPolynome<i32> = Coeff(1i32) * X * X + Coeff(5i32);
+    let components = sparse.homogeneous_components();
+    assert_eq!(components[1], TypedPolynome::zero());
+    assert!(TypedPolynome::<i32>::zero().homogeneous_components().is_empty());
+}
+
+#[test]
+fn monome_cmp_by_vars_ignores_coefficient() {
+    let cheap: TypedMonome<i32> = Coeff(1i32) * Y;
+    let pricey: TypedMonome<i32> = Coeff(100i32) * X;
+    // The derived, structural order compares coefficients first.
+    assert!(cheap < pricey);
+    // The monomial order does not.
+    assert_eq!(cheap.cmp_by_vars(&pricey), std::cmp::Ordering::Greater);
+
+    let mut monomes = vec![cheap.clone(), pricey.clone()];
+    monomes.sort_by(TypedMonome::cmp_by_vars);
+    assert_eq!(monomes, vec![pricey, cheap]);
+}
+
+#[test]
+fn polynome_alternate_display() {
+    let mut polynome: TypedPolynome<i32> =
+        Coeff(2i32) * X * X + Coeff(3i32) * Y + Coeff(-1i32);
+    polynome.order();
+    assert_eq!(format!("{}", polynome), "-1 + 2*x_0^2 + 3*x_1");
+    assert_eq!(format!("{:#}", polynome), "  -1\n+ 2*x_0^2\n+ 3*x_1");
+    assert_eq!(format!("{:#}", TypedPolynome::<i32>::zero()), "0");
+
+    let mut negated: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(-3i32) * Y;
+    negated.order();
+    assert_eq!(format!("{:#}", negated), "  1*x_0\n- 3*x_1");
+}
+
+#[test]
+fn monome_checked_pow() {
+    let monome: TypedMonome<u32> = Coeff(3u32) * X;
+    let cube = monome.clone().checked_pow(3).unwrap();
+    assert_eq!(cube.coeff, 27);
+    assert_eq!(cube.vars, X * X * X);
+
+    // 1000^10 overflows u32 where the wrapping Pow would return garbage.
+    let large: TypedMonome<u32> = Coeff(1000u32) * X;
+    assert_eq!(large.checked_pow(10), None);
+}
+
+#[test]
+fn polynome_mul_output_order_is_pinned() {
+    let left: TypedPolynome<i32> = TypedPolynome::from(X) + Y;
+    let right: TypedPolynome<i32> = TypedPolynome::from(Z) + X;
+    let product = left * right;
+    // Mul returns the canonical ordered form; this exact vec is contractual.
+    assert_eq!(
+        product.monomes,
+        vec![
+            Coeff(1i32) * X * Y,
+            Coeff(1i32) * X * Z,
+            Coeff(1i32) * X * X,
+            Coeff(1i32) * Y * Z,
+        ]
+    );
+}
+
+#[test]
+fn polynome_promote() {
+    let narrow: TypedPolynome<i32> = Coeff(2i32) * X + Coeff(3i32);
+    let wide: TypedPolynome<i64> = (Coeff(5i64) * X).into();
+    let sum = narrow.promote::<i64>() + wide;
+    let mut expected: TypedPolynome<i64> = Coeff(7i64) * X + Coeff(3i64);
+    expected.order();
+    assert!(sum.equivalent(&expected));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_polynome_is_normalized() {
+    use rand::Rng;
+    use rust_polynomes::random_polynome;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        let polynome =
+            random_polynome(&mut rng, 3, 4, 10, |rng: &mut rand::rngs::ThreadRng| {
+                rng.gen_range(-5i32..=5)
+            });
+        assert!(polynome.len() <= 10);
+        assert!(polynome.degree() <= 4);
+        assert_eq!(polynome, polynome.normalized());
+        for monome in &polynome.monomes {
+            assert_ne!(monome.coeff, 0);
+            assert!(monome.vars.powers.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+    }
+}
+
+#[test]
+fn polynome_substitute_cached_matches_substitute() {
+    let mut polynome: TypedPolynome<i64> = TypedPolynome::zero();
+    for power in 0..=20usize {
+        polynome += TypedMonome {
+            coeff: (power + 1) as i64,
+            vars: UntypedMonome {
+                powers: if power == 0 { vec![] } else { vec![(0, power)] },
+            },
+        };
+    }
+    assert_eq!(
+        polynome.substitute_cached(vec![(X, 2i64)]),
+        polynome.substitute(vec![(X, 2i64)])
+    );
+
+    let mixed: TypedPolynome<i64> = Coeff(1i64) * X * X * Y + Coeff(1i64) * X * X * Z;
+    assert_eq!(
+        mixed.substitute_cached(vec![(X, 3i64), (Y, 4i64), (Z, 5i64)]),
+        Ok(9 * 4 + 9 * 5)
+    );
+    assert_eq!(
+        mixed.substitute_cached(vec![(X, 1i64), (X, 2i64), (Y, 0i64), (Z, 0i64)]),
+        Err(SubstitutionError::RepeatingVariable(0))
+    );
+    assert_eq!(
+        mixed.substitute_cached(vec![(X, 1i64)]),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn typed_monome_iter_powers_and_coeff() {
+    let monome: TypedMonome<i32> = Coeff(7i32) * X * Y * Y;
+    assert_eq!(*monome.coeff(), 7);
+    let pairs: Vec<_> = monome.iter_powers().collect();
+    assert_eq!(pairs, vec![(X, 1), (Y, 2)]);
+}
+
+#[test]
+fn polynome_sum_of_coefficients() {
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X * X + Coeff(3i32) * Y + Coeff(-1i32);
+    assert_eq!(polynome.sum_of_coefficients(), 4);
+    assert_eq!(
+        polynome.sum_of_coefficients(),
+        polynome.substitute(vec![(X, 1i32), (Y, 1i32)]).unwrap()
+    );
+    assert_eq!(TypedPolynome::<i32>::zero().sum_of_coefficients(), 0);
+}
+
+#[test]
+fn polynome_approx_eq() {
+    let noisy: TypedPolynome<f64> =
+        Coeff(1.0 + 1e-12) * X * X + Coeff(2.0) * X * Y + Coeff(1e-12) * Y;
+    let target: TypedPolynome<f64> = Coeff(1.0) * X * X + Coeff(2.0) * X * Y;
+    assert!(noisy.approx_eq(&target, 1e-9));
+    assert!(!noisy.approx_eq(&target, 1e-15));
+
+    let different: TypedPolynome<f64> = Coeff(1.0) * X * X + Coeff(2.5) * X * Y;
+    assert!(!noisy.approx_eq(&different, 1e-9));
+}
+
+#[test]
+fn monome_subtraction() {
+    let difference = Coeff(3i32) * X - Coeff(2i32) * X;
+    let mut expected: TypedPolynome<i32> = (Coeff(1i32) * X).into();
+    expected.order();
+    assert!(difference.equivalent(&expected));
+
+    let with_constant = Coeff(5i32) - Coeff(2i32) * Y;
+    let mut expected: TypedPolynome<i32> = Coeff(5i32) + Coeff(-2i32) * Y;
+    expected.order();
+    assert!(with_constant.equivalent(&expected));
+}
+
+#[test]
+fn polynome_ordered_in_expression_position() {
+    let polynome: TypedPolynome<i32> = Coeff(1i32) * Y + Coeff(1i32) * X + Coeff(1i32) * X;
+    assert_eq!(polynome.to_ordered(), polynome.clone().ordered());
+    assert_eq!(
+        polynome.ordered(),
+        (Coeff(2i32) * X + Coeff(1i32) * Y).ordered()
+    );
+}
+
+#[test]
+fn polynome_reduce_mod() {
+    let polynome: TypedPolynome<i64> = Coeff(7i64) * X + Coeff(-3i64) * Y + Coeff(10i64);
+    let mut expected: TypedPolynome<i64> = Coeff(2i64) * X + Coeff(2i64) * Y;
+    expected.order();
+    assert_eq!(polynome.reduce_mod(5), expected);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn polynome_is_probably_zero() {
+    let mut rng = rand::thread_rng();
+    // (x + y)^2 - (x^2 + 2xy + y^2) is identically zero.
+    let square: TypedPolynome<i64> = TypedPolynome::from((X + Y) * (X + Y));
+    let expansion: TypedPolynome<i64> =
+        Coeff(1i64) * X * X + Coeff(2i64) * X * Y + Coeff(1i64) * Y * Y;
+    let identity = square - expansion.clone();
+    for _ in 0..10 {
+        assert!(identity.is_probably_zero(&mut rng, 1_000_003));
+    }
+    let mut misses = 0;
+    for _ in 0..10 {
+        if !expansion.is_probably_zero(&mut rng, 1_000_003) {
+            misses += 1;
+        }
+    }
+    assert!(misses > 0);
+}
+
+#[test]
+fn polynome_scale() {
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X + Coeff(3i32);
+    let scaled = polynome.scale(2);
+    let mut expected: TypedPolynome<i32> = Coeff(4i32) * X + Coeff(6i32);
+    expected.order();
+    assert!(scaled.equivalent(&expected));
+    assert_eq!(polynome.scale(0).normalized(), TypedPolynome::zero());
+}
+
+#[test]
+fn monome_new_merges_repeated_indices() {
+    let monome = TypedMonome::new(
+        2i32,
+        UntypedMonome {
+            powers: vec![(0, 1), (0, 1)],
+        },
+    );
+    assert_eq!(monome, Coeff(2i32) * X * X);
+
+    let (power, rest) = TypedMonome {
+        coeff: 3i32,
+        vars: UntypedMonome {
+            powers: vec![(0, 1), (0, 1), (1, 2)],
+        },
+    }
+    .extract_variable(X);
+    assert_eq!(power, 2);
+    assert_eq!(rest, Coeff(3i32) * Y * Y);
+}
+
+#[test]
+fn polynome_taylor_coefficients() {
+    let square: TypedPolynome<f64> = (Coeff(1.0) * X * X).into();
+    assert_eq!(
+        square.taylor_coefficients(X, 1.0, 2).unwrap(),
+        vec![1.0, 2.0, 1.0]
+    );
+    // Coefficients past the degree vanish.
+    assert_eq!(
+        square.taylor_coefficients(X, 0.0, 3).unwrap(),
+        vec![0.0, 0.0, 1.0, 0.0]
+    );
+    let bivariate: TypedPolynome<f64> = (Coeff(1.0) * X * Y).into();
+    assert_eq!(
+        bivariate.taylor_coefficients(X, 0.0, 1),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn polynome_assign_operators_accept_references() {
+    let factors: Vec<TypedPolynome<i32>> = vec![
+        Coeff(1i32) * X + Coeff(1i32),
+        Coeff(1i32) * X + Coeff(-1i32),
+    ];
+    let mut product = TypedPolynome::one();
+    let mut sum = TypedPolynome::zero();
+    for factor in &factors {
+        product *= factor;
+        sum += factor;
+    }
+    assert_eq!(product, (factors[0].clone() * factors[1].clone()).ordered());
+    assert!(sum.equivalent(&(Coeff(2i32) * X).into()));
+}
+
+#[test]
+fn map_polynome_round_trip_and_lookup() {
+    use rust_polynomes::MapPolynome;
+
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X * Y + Coeff(3i32) * X + Coeff(1i32);
+    let mut map = MapPolynome::from(polynome.clone());
+    assert_eq!(map.get(&(X * Y)), Some(&2));
+    assert_eq!(map.get(&(Y * Y)), None);
+
+    map.add_term(X.into(), -3);
+    assert_eq!(map.get(&X.into()), None);
+    assert_eq!(map.remove(&UntypedMonome::default()), Some(1));
+
+    let back = TypedPolynome::from(map);
+    assert_eq!(back, TypedPolynome::from(Coeff(2i32) * X * Y));
+    assert_eq!(back, back.normalized());
+}
+
+#[test]
+fn map_polynome_arithmetic_matches_vec_form() {
+    use rust_polynomes::MapPolynome;
+
+    let left: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(1i32) * Y;
+    let right: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(-1i32) * Y;
+    let sum = MapPolynome::from(left.clone()) + MapPolynome::from(right.clone());
+    assert_eq!(
+        TypedPolynome::from(sum),
+        (left.clone() + right.clone()).ordered()
+    );
+    let product = MapPolynome::from(left.clone()) * MapPolynome::from(right.clone());
+    assert_eq!(TypedPolynome::from(product), left * right);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_mul_agrees_with_sequential_mul() {
+    // Deterministic pseudo-random inputs from a small LCG.
+    let mut state = 42u64;
+    let mut next = move |bound: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % bound
+    };
+    for _ in 0..5 {
+        let mut left: TypedPolynome<i64> = TypedPolynome::zero();
+        let mut right: TypedPolynome<i64> = TypedPolynome::zero();
+        for _ in 0..20 {
+            left += TypedMonome::new(
+                next(19) as i64 - 9,
+                UntypedMonome::from_powers(vec![(0, next(4) as usize), (1, next(4) as usize)]),
+            );
+            right += TypedMonome::new(
+                next(19) as i64 - 9,
+                UntypedMonome::from_powers(vec![(1, next(4) as usize), (2, next(4) as usize)]),
+            );
+        }
+        assert_eq!(left.par_mul(&right), left.clone() * right.clone());
+    }
+}
+
+#[test]
+fn polynome_into_fn() {
+    let polynome: TypedPolynome<i32> = Coeff(2i32) * X * X + Coeff(1i32) * Y;
+    let evaluate = polynome.clone().into_fn();
+    assert_eq!(evaluate(&[(X, 3), (Y, 1)]), Ok(19));
+    assert_eq!(
+        evaluate(&[(X, 3), (Y, 1)]),
+        polynome.substitute(vec![(X, 3), (Y, 1)])
+    );
+    assert_eq!(
+        evaluate(&[(X, 3)]),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+    assert_eq!(
+        evaluate(&[(X, 1), (X, 2), (Y, 0)]),
+        Err(SubstitutionError::RepeatingVariable(0))
+    );
+}
+
+#[test]
+fn polynome_from_term_lists() {
+    let from_array = TypedPolynome::from([Coeff(1i32) * Y, Coeff(2i32) * X, Coeff(1i32) * Y]);
+    let from_vec = TypedPolynome::from(vec![Coeff(2i32) * X, Coeff(2i32) * Y]);
+    assert_eq!(from_array, from_vec);
+    assert_eq!(from_array, from_array.normalized());
+
+    let table: TypedPolynome<i32> = TypedPolynome::from([
+        (2i32, &[(X, 1), (Y, 1)][..]),
+        (3i32, &[][..]),
+    ]);
+    assert_eq!(table, (Coeff(2i32) * X * Y + Coeff(3i32)).ordered());
+}
+
+#[test]
+fn polynome_checked_sub() {
+    let counts: TypedPolynome<u32> = Coeff(3u32) * X + Coeff(2u32) * Y;
+    let fewer: TypedPolynome<u32> = Coeff(1u32) * X + Coeff(2u32) * Y;
+    let difference = counts.checked_sub(&fewer).unwrap();
+    assert_eq!(difference, TypedPolynome::from(Coeff(2u32) * X));
+
+    // Underflow in any coefficient rejects the whole subtraction.
+    assert_eq!(fewer.checked_sub(&counts), None);
+    let missing: TypedPolynome<u32> = (Coeff(1u32) * Z).into();
+    assert_eq!(counts.checked_sub(&missing), None);
+}
+
+#[test]
+fn polynome_as_univariate() {
+    let polynome: TypedPolynome<i32> =
+        Coeff(1i32) * X * X * Y + Coeff(1i32) * X * Z + Coeff(1i32);
+    let groups = polynome.as_univariate(X);
+    assert_eq!(
+        groups,
+        vec![
+            (0, TypedPolynome::one()),
+            (1, TypedPolynome::from(Coeff(1i32) * Z)),
+            (2, TypedPolynome::from(Coeff(1i32) * Y)),
+        ]
+    );
+    // Cancelling coefficients drop their power entirely.
+    let cancelling: TypedPolynome<i32> = Coeff(1i32) * X * Y + Coeff(-1i32) * X * Y;
+    assert!(cancelling.as_univariate(X).is_empty());
+}
+
+#[test]
+fn polynome_cmp_normalized() {
+    use std::cmp::Ordering;
+
+    let unordered: TypedPolynome<i32> = Coeff(1i32) * Y + Coeff(1i32) * X;
+    let ordered = unordered.normalized();
+    // The derived Ord is structural and disagrees; the normalized one does not.
+    assert_ne!(unordered.cmp(&ordered), Ordering::Equal);
+    assert_eq!(unordered.cmp_normalized(&ordered), Ordering::Equal);
+
+    let smaller: TypedPolynome<i32> = (Coeff(1i32) * X).into();
+    assert_eq!(smaller.cmp_normalized(&ordered), Ordering::Less);
+}
+
+#[test]
+fn tropical_substitution_finds_shortest_path() {
+    use num_traits::{One, Zero};
+    use rust_polynomes::semirings::Tropical;
+
+    // Two parallel routes: via x then y, or directly via z. Tropical
+    // addition takes the minimum, multiplication adds weights.
+    let routes: TypedPolynome<Tropical> =
+        Coeff(Tropical::one()) * X * Y + Coeff(Tropical::one()) * Z;
+    let shortest = routes
+        .substitute(vec![
+            (X, Tropical(2.0)),
+            (Y, Tropical(3.0)),
+            (Z, Tropical(6.0)),
+        ])
+        .unwrap();
+    assert_eq!(shortest, Tropical(5.0));
+
+    // An unreachable route costs the additive identity, +infinity.
+    let detour = routes
+        .substitute(vec![
+            (X, Tropical::zero()),
+            (Y, Tropical(3.0)),
+            (Z, Tropical(6.0)),
+        ])
+        .unwrap();
+    assert_eq!(detour, Tropical(6.0));
+}
+
+#[test]
+fn polynome_divide_by_monomial() {
+    let polynome: TypedPolynome<f64> = Coeff(2.0) * X * X * Y + Coeff(2.0) * X * Y;
+    let quotient = polynome.divide_by_monomial(2.0, &(X * Y)).unwrap();
+    assert!(quotient.equivalent(&(Coeff(1.0) * X + Coeff(1.0)).ordered()));
+
+    // A term with a smaller power in some variable is not divisible.
+    let mixed: TypedPolynome<f64> = Coeff(1.0) * X * Y + Coeff(1.0) * X;
+    assert_eq!(mixed.divide_by_monomial(1.0, &(X * Y)), None);
+    assert_eq!(mixed.divide_by_monomial(0.0, &X.into()), None);
+}
+
+#[test]
+fn polynome_interpolate() {
+    let points = [(0.0, 1.0), (1.0, 2.0), (2.0, 5.0)];
+    let fitted = interpolate(&points, X).unwrap();
+    let expected = (Coeff(1.0) * X * X + Coeff(1.0)).ordered();
+    assert!(fitted.approx_eq(&expected, 1e-9));
+    for (x, y) in points {
+        assert!((fitted.substitute(vec![(X, x)]).unwrap() - y).abs() < 1e-9);
+    }
+
+    assert_eq!(interpolate(&[(1.0, 2.0), (1.0, 3.0)], X), None);
+    assert_eq!(interpolate::<f64>(&[], X), Some(TypedPolynome::zero()));
+}
+
+#[test]
+fn pow_ref_borrows_the_base() {
+    let base: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(1i32);
+    let mut sum = TypedPolynome::zero();
+    for exponent in 0..4usize {
+        sum += base.pow_ref(exponent);
+    }
+    let mut expected = TypedPolynome::one();
+    for exponent in 1..4usize {
+        expected += base.clone().pow(exponent);
+    }
+    assert_eq!(sum.ordered(), expected.ordered());
+
+    let monome: TypedMonome<i32> = Coeff(2i32) * X;
+    assert_eq!(monome.pow_ref(3), monome.clone().pow(3));
+    assert_eq!((X * Y).pow_ref(2), (X * Y).pow(2usize));
+}
+
+#[test]
+fn errors_implement_display_and_error() {
+    use rust_polynomes::errors::PolynomeError;
+
+    fn propagate(polynome: &TypedPolynome<i32>) -> Result<i32, Box<dyn std::error::Error>> {
+        Ok(polynome.substitute(vec![(X, 1i32)])?)
+    }
+
+    let polynome: TypedPolynome<i32> = (Coeff(1i32) * X * Y).into();
+    let error = propagate(&polynome).unwrap_err();
+    assert_eq!(error.to_string(), "missing variable x_1");
+
+    let unified: PolynomeError = SubstitutionError::RepeatingVariable(0).into();
+    assert_eq!(unified.to_string(), "repeating variable x_0");
+    assert_eq!(
+        PolynomeError::from(DivisionError::ZeroDivisor).to_string(),
+        "division by zero"
+    );
+}
+
+#[test]
+fn polynome_eval_mod_p() {
+    let polynome: TypedPolynome<i64> = Coeff(1i64) * X * X + Coeff(-1i64) * Y;
+    assert_eq!(polynome.eval_mod_p(&[(X, 3), (Y, 2)], 5), Ok(2));
+    // Large values and high degrees stay in range thanks to stepwise reduction.
+    let tower = TypedPolynome::from_coefficients(X, vec![0; 40])
+        + TypedMonome::new(1i64, UntypedMonome::from_powers(vec![(0, 40)]));
+    let value = tower.eval_mod_p(&[(X, i64::MAX - 1)], 1_000_000_007).unwrap();
+    assert!((0..1_000_000_007).contains(&value));
+    assert_eq!(
+        polynome.eval_mod_p(&[(X, 1)], 5),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn polynome_substitute_constant() {
+    let mut polynome = Coeff(2u32) * X * X * Y + Coeff(1u32) * Y + Coeff(5u32);
+    polynome.substitute_constant(X, 3u32);
+    assert_eq!(polynome, (Coeff(19u32) * Y + Coeff(5u32)).to_ordered());
+    polynome.substitute_constant(Y, 1u32);
+    assert_eq!(polynome, Coeff(24u32).into());
+}
+
+#[test]
+fn polynome_common_monomial_factor() {
+    let polynome = Coeff(2i64) * X * X * Y + Coeff(4i64) * X * Y;
+    let (coeff, monome) = polynome.common_monomial_factor();
+    assert_eq!(coeff, 2);
+    assert_eq!(monome, X * Y);
+
+    let polynome = Coeff(0.5f64) * X * Y + Coeff(1.5f64) * X;
+    let (coeff, monome) = polynome.common_monomial_factor();
+    assert_eq!(coeff, 1.0);
+    assert_eq!(monome, X.into());
+    let reduced = polynome.divide_by_monomial(coeff, &monome).unwrap();
+    assert_eq!(reduced, Coeff(0.5f64) * Y + Coeff(1.5f64));
+
+    let zero = TypedPolynome::<i64>::zero();
+    assert_eq!(zero.common_monomial_factor(), (0, UntypedMonome::default()));
+}
+
+#[test]
+fn polynome_substitute_polynomes() {
+    let polynome: TypedPolynome<i64> = (Coeff(1i64) * X * Y).into();
+    let substituted = polynome
+        .substitute_polynomes(vec![
+            (X, Coeff(1i64) * X + Coeff(1i64) * Y),
+            (Y, Coeff(1i64) * X - Coeff(1i64) * Y),
+        ])
+        .unwrap();
+    assert_eq!(
+        substituted,
+        (Coeff(1i64) * X * X - Coeff(1i64) * Y * Y).to_ordered()
+    );
+    assert_eq!(polynome, (Coeff(1i64) * X * Y).into());
+    assert_eq!(
+        polynome.substitute_polynomes(vec![
+            (X, TypedPolynome::zero()),
+            (X, TypedPolynome::zero()),
+        ]),
+        Err(SubstitutionError::RepeatingVariable(0))
+    );
+}
+
+#[test]
+fn polynome_terms_by_degree() {
+    let polynome = Coeff(1u32) * X * X * X + Coeff(2u32) * X * Y + Coeff(3u32) + Coeff(4u32) * Y
+        + Coeff(0u32) * X
+        + Coeff(1u32) * X * Y;
+    let degrees: Vec<usize> = polynome
+        .terms_by_degree()
+        .map(|monome| monome.degree())
+        .collect();
+    assert_eq!(degrees, vec![0, 1, 2, 3]);
+    let terms: Vec<TypedMonome<u32>> = polynome.terms_by_degree().collect();
+    assert_eq!(terms[2], Coeff(3u32) * X * Y);
+}
+
+#[test]
+fn polynome_from_numeric_constant() {
+    let polynome: TypedPolynome<i32> = 5.into();
+    assert_eq!(polynome, Coeff(5i32).into());
+    let polynome: TypedPolynome<f64> = 2.5.into();
+    assert_eq!(polynome, Coeff(2.5f64).into());
+    assert_eq!(TypedPolynome::from(3u8) + Coeff(1u8) * X, Coeff(3u8) + Coeff(1u8) * X);
+}
+
+#[test]
+fn polynome_max_variable() {
+    let polynome = Coeff(1i64) * X + Coeff(2i64) * Z + Coeff(0i64) * Var(7);
+    assert_eq!(polynome.max_variable(), Some(Z));
+    assert_eq!(TypedPolynome::from(Coeff(3i64)).max_variable(), None);
+    assert_eq!(TypedPolynome::<i64>::zero().max_variable(), None);
+    let untyped = X * Y + Z;
+    assert_eq!(untyped.max_variable(), Some(Z));
+}
+
+#[test]
+fn polynome_validate_finite() {
+    let polynome = Coeff(1.0f64) * X + Coeff(2.0f64) * Y;
+    assert_eq!(polynome.validate_finite(), Ok(()));
+    let polynome = Coeff(1.0f64) * X + Coeff(f64::NAN) * Y + Coeff(f64::INFINITY);
+    assert_eq!(polynome.validate_finite(), Err(1));
+    // order() sorts by the variable part alone, so a NaN coefficient
+    // cannot destabilize it.
+    let ordered = polynome.to_ordered();
+    assert!(ordered.monomes[2].coeff.is_nan());
+}
+
+#[test]
+fn polynome_extend_terms() {
+    let mut polynome = TypedPolynome::<i64>::zero();
+    polynome.extend_terms(vec![
+        (2, vec![(X, 1), (Y, 1), (X, 1)]),
+        (3, vec![]),
+        (1, vec![(Y, 2)]),
+    ]);
+    polynome.order();
+    assert_eq!(
+        polynome,
+        (Coeff(2i64) * X * X * Y + Coeff(3i64) + Coeff(1i64) * Y * Y).to_ordered()
+    );
+}
+
+#[test]
+fn polynome_substitute_dense() {
+    let mut pool = VarPool::new();
+    let a = pool.named("a");
+    let b = pool.named("b");
+    assert_eq!(pool.index_of("b"), Some(b));
+    assert_eq!(pool.index_of("c"), None);
+    assert_eq!(pool.fresh(), Var(2));
+    assert_eq!(pool.len(), 3);
+
+    let polynome = Coeff(2i64) * a * a + Coeff(1i64) * b;
+    assert_eq!(polynome.substitute_dense(&pool, &[3, 4, 0]), Ok(22));
+    assert_eq!(
+        polynome.substitute_dense(&pool, &[3]),
+        Err(SubstitutionError::MissingVariable(1))
+    );
+}
+
+#[test]
+fn polynome_negate_in_place() {
+    let mut polynome = Coeff(2i64) * X * Y + Coeff(-3i64) * Z;
+    polynome.negate_in_place();
+    assert_eq!(polynome, Coeff(-2i64) * X * Y + Coeff(3i64) * Z);
+    assert_eq!(polynome.clone(), -(-polynome.clone()));
+    polynome.negate_in_place();
+    assert_eq!(
+        (polynome - TypedPolynome::from(Coeff(2i64) * X * Y)).to_ordered(),
+        (Coeff(-3i64) * Z).into()
+    );
+}
+
+#[test]
+fn polynome_to_coefficients_skips_zero_terms() {
+    let polynome = Coeff(0i32) * X * X + Coeff(1i32) * X;
+    assert_eq!(polynome.to_coefficients(X), Ok(vec![0, 1]));
+}
+
+#[test]
+fn polynome_eval_horner_skips_zero_terms() {
+    let polynome = Coeff(0i32) * X * X + Coeff(1i32) * X;
+    assert_eq!(polynome.eval_horner(X, 2), Ok(2));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn polynome_is_probably_zero_high_degree() {
+    use num_traits::Pow;
+
+    let mut rng = rand::thread_rng();
+    let base: TypedPolynome<i64> = Coeff(1i64) * X + Coeff(1i64) * Y;
+    // Degree 8: evaluating without stepwise reduction would overflow even
+    // i128 well before this over a million-element field.
+    let zero = base.clone().pow(4) * base.clone().pow(4) - base.clone().pow(8);
+    assert!(zero.is_probably_zero(&mut rng, 1_000_003));
+    let nonzero = base.pow(8) + Coeff(1i64) * X;
+    assert!(!nonzero.is_probably_zero(&mut rng, 1_000_003));
+}
@@ -0,0 +1,221 @@
+use num_traits::Pow;
+use rust_polynomes::variables::{X, Y, Z};
+use rust_polynomes::{Coeff, TypedPolynome, UntypedMonome, UntypedPolynome};
+
+#[test]
+fn monome_multiplication_merges_powers() {
+    let monome: UntypedMonome = X * Y * X * Z;
+    assert_eq!(monome.powers, vec![(0, 2), (1, 1), (2, 1)]);
+}
+
+#[test]
+fn monome_pow() {
+    let monome: UntypedMonome = (X * Y).pow(3usize);
+    assert_eq!(monome.powers, vec![(0, 3), (1, 3)]);
+    assert_eq!((X * Y).pow(0usize), UntypedMonome::default());
+}
+
+#[test]
+fn monome_degree() {
+    let monome: UntypedMonome = X * X * Y;
+    assert_eq!(monome.degree(), 3);
+    assert_eq!(UntypedMonome::default().degree(), 0);
+}
+
+#[test]
+fn monome_degree_in() {
+    let monome: UntypedMonome = X * X * Y;
+    assert_eq!(monome.degree_in(X), 2);
+    assert_eq!(monome.degree_in(Y), 1);
+    assert_eq!(monome.degree_in(Z), 0);
+}
+
+#[test]
+fn monome_contains_variable() {
+    let monome: UntypedMonome = X * Y;
+    assert!(monome.contains_variable(X));
+    assert!(!monome.contains_variable(Z));
+    assert!(!UntypedMonome::default().contains_variable(X));
+}
+
+#[test]
+fn polynome_addition_and_multiplication() {
+    let polynome: UntypedPolynome = (X + Y) * (X + Z);
+    assert_eq!(polynome.monomes.len(), 4);
+    assert_eq!(
+        polynome.monomes,
+        vec![X * X, X * Z, Y * X, Y * Z]
+    );
+}
+
+#[test]
+fn polynome_variables() {
+    let polynome: UntypedPolynome = X * Z + Y;
+    assert_eq!(polynome.variables(), vec![X, Y, Z]);
+    assert_eq!(UntypedPolynome::default().variables(), vec![]);
+}
+
+#[test]
+fn polynome_order_sorts_monomes() {
+    let mut polynome: UntypedPolynome = Y * Z + X * Y;
+    polynome.order();
+    assert_eq!(polynome.monomes, vec![X * Y, Y * Z]);
+}
+
+#[test]
+fn polynome_collect_terms() {
+    let polynome = (X + Y).pow(2usize);
+    assert_eq!(
+        polynome.collect_terms(),
+        vec![(X * Y, 2), (X * X, 1), (Y * Y, 1)]
+    );
+    assert_eq!(UntypedPolynome::default().collect_terms(), vec![]);
+}
+
+#[test]
+fn polynome_pow_zero_is_one() {
+    let polynome = (X + Y).pow(0usize);
+    assert_eq!(
+        polynome,
+        UntypedPolynome {
+            monomes: vec![UntypedMonome::default()],
+        }
+    );
+}
+
+#[test]
+fn polynome_pow() {
+    let mut polynome: UntypedPolynome = (X + Y).pow(2usize);
+    polynome.order();
+    assert_eq!(polynome.monomes, vec![X * Y, X * Y, X * X, Y * Y]);
+}
+
+#[test]
+fn polynome_into_typed() {
+    let polynome = X * Y + X * Y + X;
+    let typed = polynome.into_typed::<i32>();
+    let mut expected: TypedPolynome<i32> = Coeff(2i32) * X * Y + Coeff(1i32) * X;
+    expected.order();
+    assert_eq!(typed, expected);
+
+    let difference = (X + Y).into_typed::<i32>() - (Y + Y).into_typed::<i32>();
+    let mut expected: TypedPolynome<i32> = Coeff(1i32) * X + Coeff(-1i32) * Y;
+    expected.order();
+    assert!(difference.equivalent(&expected));
+}
+
+#[test]
+fn polynome_count_terms() {
+    let expansion = (X + Y).pow(2usize);
+    let mut expected: TypedPolynome<u64> =
+        Coeff(1u64) * X * X + Coeff(2u64) * X * Y + Coeff(1u64) * Y * Y;
+    expected.order();
+    assert_eq!(expansion.count_terms(), expected);
+    assert_eq!(UntypedPolynome::default().count_terms(), TypedPolynome::zero());
+}
+
+#[test]
+fn monome_derivative() {
+    let cube: UntypedMonome = X * X * X;
+    let mut expected: TypedPolynome<i64> = (Coeff(3i64) * X * X).into();
+    expected.order();
+    assert!(cube.derivative(X).equivalent(&expected));
+
+    let mixed: UntypedMonome = X * X * Y;
+    let mut expected: TypedPolynome<i64> = (Coeff(2i64) * X * Y).into();
+    expected.order();
+    assert!(mixed.derivative(X).equivalent(&expected));
+
+    assert_eq!(UntypedMonome::default().derivative(X), TypedPolynome::zero());
+    assert_eq!(mixed.derivative(Z), TypedPolynome::zero());
+}
+
+#[test]
+fn polynome_mul_output_order_is_pinned() {
+    let product = (X + Y) * (Z + X);
+    // Outer loop over the left operand, inner over the right.
+    assert_eq!(product.monomes, vec![X * Z, X * X, Y * Z, Y * X]);
+}
+
+#[test]
+fn const_variables_interop() {
+    use rust_polynomes::const_variables;
+
+    let monome: UntypedMonome = const_variables::X * const_variables::Y;
+    assert_eq!(monome, X * Y);
+    let mixed: UntypedMonome = const_variables::X * Y;
+    assert_eq!(mixed, X * Y);
+    assert_eq!(rust_polynomes::variables::Var::from(const_variables::Z), Z);
+
+    let mut polynome = const_variables::X + const_variables::Y * const_variables::Y;
+    polynome.order();
+    let mut expected = X + Y * Y;
+    expected.order();
+    assert_eq!(polynome, expected);
+}
+
+#[test]
+fn monome_iter_powers() {
+    let monome: UntypedMonome = X * X * Z;
+    let pairs: Vec<_> = monome.iter_powers().collect();
+    assert_eq!(pairs, vec![(X, 2), (Z, 1)]);
+    assert_eq!(UntypedMonome::default().iter_powers().count(), 0);
+}
+
+#[test]
+fn monome_from_powers_normalizes() {
+    let monome = UntypedMonome::from_powers(vec![(1, 1), (0, 1), (0, 1), (2, 0)]);
+    assert_eq!(monome, X * X * Y);
+    assert_eq!(UntypedMonome::from_powers(vec![]), UntypedMonome::default());
+}
+
+#[test]
+fn monome_canonicalize_strips_zero_powers() {
+    let mut spurious = UntypedMonome {
+        powers: vec![(0, 1), (1, 0)],
+    };
+    let clean: UntypedMonome = X.into();
+    assert_ne!(spurious, clean);
+    spurious.canonicalize();
+    assert_eq!(spurious, clean);
+}
+
+#[test]
+fn polynome_ordered_in_expression_position() {
+    let polynome = Y + X;
+    assert_eq!(polynome.to_ordered(), (X + Y).ordered());
+    assert_eq!(polynome.ordered().monomes, vec![X.into(), Y.into()]);
+}
+
+#[test]
+fn polynome_subtraction_promotes_to_i64() {
+    let difference = X - Y;
+    let mut expected: TypedPolynome<i64> = Coeff(1i64) * X + Coeff(-1i64) * Y;
+    expected.order();
+    assert!(difference.equivalent(&expected));
+
+    let cancelled = (X + Y) - (Y + X);
+    assert!(cancelled.equivalent(&TypedPolynome::zero()));
+
+    let negated = -(X + X);
+    assert!(negated.equivalent(&(Coeff(-2i64) * X).into()));
+}
+
+#[test]
+fn symmetric_constructors() {
+    use rust_polynomes::symmetric::{elementary_symmetric, power_sum};
+
+    let elementary = elementary_symmetric(&[X, Y, Z], 2);
+    assert_eq!(elementary, (X * Y + X * Z + Y * Z).to_ordered());
+    assert_eq!(elementary_symmetric(&[X, Y], 0).monomes.len(), 1);
+    assert_eq!(elementary_symmetric(&[X, Y], 3), UntypedPolynome::default());
+    assert_eq!(
+        elementary_symmetric(&[Y, X, Y], 1),
+        (X + Y).to_ordered()
+    );
+
+    let sums = power_sum(&[X, Y], 2);
+    assert_eq!(sums, (X * X + Y * Y).to_ordered());
+    let constant = power_sum(&[X, Y, Z, Y], 0);
+    assert_eq!(constant.monomes, vec![UntypedMonome::default(); 3]);
+}